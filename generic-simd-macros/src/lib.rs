@@ -1,73 +1,671 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
+use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, Ident, ItemFn};
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    Error, FnArg, GenericParam, Ident, ImplItem, ImplItemMethod, Item, ItemFn, ItemImpl, LitStr,
+    Pat, PatIdent, Path, Result, Signature, Token, Type,
+};
+
+struct Args {
+    token: Ident,
+    report: bool,
+    versions: Vec<Version>,
+    // Whether the fallback version tries the portable token before the generic token.
+    portable: bool,
+}
+
+// A token that gets a clone of the dispatched function.
+struct Version {
+    // The `multiversion` target, such as `[x86|x86_64]+avx`.
+    target: String,
+    token: Path,
+    // The name returned by the version report function.
+    name: String,
+    // Whether the clone requires the `simd128` target feature.
+    wasm: bool,
+}
+
+impl Version {
+    fn builtin(name: &str) -> Option<Self> {
+        let (target, token, wasm) = match name {
+            "X86V3" => (
+                "[x86|x86_64]+avx2+bmi1+bmi2+f16c+fma+lzcnt+popcnt",
+                quote! { x86::X86V3 },
+                false,
+            ),
+            "Avx" => ("[x86|x86_64]+avx", quote! { x86::Avx }, false),
+            "X86V2" => (
+                "[x86|x86_64]+sse3+ssse3+sse4.1+sse4.2+popcnt",
+                quote! { x86::X86V2 },
+                false,
+            ),
+            "Sse" => ("[x86|x86_64]+sse4.1", quote! { x86::Sse }, false),
+            "Sse2" => ("[x86|x86_64]+sse2", quote! { x86::Sse2 }, false),
+            "Simd128" => ("wasm32+simd128", quote! { wasm::Simd128 }, true),
+            "Neon" => ("aarch64+neon", quote! { arm::Neon }, false),
+            _ => return None,
+        };
+        Some(Self {
+            target: target.to_string(),
+            token: parse_quote! { generic_simd::arch::#token },
+            name: name.to_lowercase(),
+            wasm,
+        })
+    }
+
+    // NEON and SIMD128 are only available with the `nightly` feature.
+    fn available(&self) -> bool {
+        cfg!(feature = "nightly") || !(self.wasm || self.target.starts_with("aarch64"))
+    }
+
+    // The target architectures and CPU features.
+    fn archs_and_features(&self) -> (Vec<String>, Vec<String>) {
+        let mut parts = self.target.split('+');
+        let archs = parts
+            .next()
+            .unwrap()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split('|')
+            .map(String::from)
+            .collect();
+        (archs, parts.map(String::from).collect())
+    }
+}
+
+// A token in a `tokens` or `exclude` list, optionally with a `multiversion` target.
+struct TokenSpec {
+    path: Path,
+    target: Option<LitStr>,
+}
+
+impl TokenSpec {
+    fn name(&self) -> String {
+        self.path.segments.last().unwrap().ident.to_string()
+    }
+}
+
+impl Parse for TokenSpec {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.parse()?;
+        let target = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self { path, target })
+    }
+}
+
+fn parse_token_list(input: ParseStream) -> Result<Vec<TokenSpec>> {
+    input.parse::<Token![=]>()?;
+    let content;
+    bracketed!(content in input);
+    Ok(
+        Punctuated::<TokenSpec, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect(),
+    )
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let token = input.parse()?;
+        let mut report = false;
+        let mut tokens = None;
+        let mut exclude = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let option: Ident = input.parse()?;
+            if option == "report" {
+                report = true;
+            } else if option == "tokens" {
+                tokens = Some(parse_token_list(input)?);
+            } else if option == "exclude" {
+                exclude = parse_token_list(input)?;
+            } else {
+                return Err(Error::new(
+                    option.span(),
+                    "expected `report`, `tokens`, or `exclude`",
+                ));
+            }
+        }
+
+        if let Some(spec) = exclude.iter().find(|spec| spec.name() == "Generic") {
+            return Err(Error::new_spanned(
+                &spec.path,
+                "`Generic` is always used as the fallback",
+            ));
+        }
+
+        // Tokens are listed in priority order, and `Generic` is always last.
+        let tokens = tokens.unwrap_or_else(|| {
+            [
+                "X86V3", "Avx", "X86V2", "Sse", "Sse2", "Simd128", "Neon", "Portable",
+            ]
+            .iter()
+            .map(|name| TokenSpec {
+                path: Ident::new(name, Span::call_site()).into(),
+                target: None,
+            })
+            .collect()
+        });
+        if let Some(spec) = exclude
+            .iter()
+            .find(|excluded| tokens.iter().all(|spec| spec.name() != excluded.name()))
+        {
+            return Err(Error::new_spanned(
+                &spec.path,
+                format!("`{}` is not one of the dispatched tokens", spec.name()),
+            ));
+        }
+        let mut versions = Vec::new();
+        let mut portable = false;
+        for spec in tokens {
+            let name = spec.name();
+            if name == "Generic" || exclude.iter().any(|excluded| excluded.name() == name) {
+                continue;
+            }
+            // The portable token doesn't require any CPU features, so it doesn't get a clone.
+            // Like NEON and SIMD128, it's only available with the `nightly` feature.
+            if name == "Portable" && spec.target.is_none() {
+                portable = cfg!(feature = "nightly");
+                continue;
+            }
+            let version = if let Some(target) = &spec.target {
+                Version {
+                    target: target.value(),
+                    token: spec.path,
+                    name: name.to_lowercase(),
+                    wasm: target.value().starts_with("wasm32"),
+                }
+            } else {
+                Version::builtin(&name).ok_or_else(|| {
+                    Error::new_spanned(
+                        &spec.path,
+                        "unknown token, specify its target with `Token = \"target\"`",
+                    )
+                })?
+            };
+            if version.available() {
+                versions.push(version);
+            }
+        }
+
+        Ok(Self {
+            token,
+            report,
+            versions,
+            portable,
+        })
+    }
+}
 
 #[proc_macro_attribute]
 pub fn dispatch(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as Args);
+    let output = match parse_macro_input!(input as Item) {
+        Item::Fn(func) => {
+            let report_name = report_name(&func.sig.ident);
+            let reported = func.sig.ident.to_string();
+            dispatch_fn(&args, func, report_name, None, reported)
+        }
+        Item::Impl(item) => dispatch_impl(&args, item),
+        item => Error::new_spanned(item, "expected a function or impl block").to_compile_error(),
+    };
+    output.into()
+}
+
+fn report_name(name: &Ident) -> Ident {
+    Ident::new(&format!("{}_selected_version", name), name.span())
+}
+
+// Converts a trait name such as `AddAssign` to `add_assign`.
+fn snake_case(ident: &Ident) -> String {
+    let mut name = String::new();
+    for (i, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+// Multiversions a function, which may be associated.
+//
+// The version report function is named `report_name`, with the visibility `report_vis` if given,
+// and documented as reporting the version of `reported`.
+fn dispatch_fn(
+    args: &Args,
+    func: ItemFn,
+    report_name: Ident,
+    report_vis: Option<syn::Visibility>,
+    reported: String,
+) -> proc_macro2::TokenStream {
     let ItemFn {
         attrs,
         vis,
         sig,
         block,
-    } = parse_macro_input!(input as ItemFn);
-    let feature = parse_macro_input!(args as Ident);
+    } = func;
+    let feature = &args.token;
+    let portable_name = Ident::new(&format!("{}_portable_version", sig.ident), sig.ident.span());
 
-    let build_fn = |wasm| {
-        let nightly = cfg!(feature = "nightly");
-        let clone_wasm = if nightly && wasm {
-            Some(quote! { #[clone(target = "wasm32+simd128")] })
-        } else {
+    // The fallback version forwards its arguments to the portable version, so the dispatched
+    // function binds every argument to an identifier.
+    let (forwarded, dispatched_sig, bindings) = if args.portable {
+        let mut dispatched_sig = sig.clone();
+        let (forwarded, bindings) = bind_args(&mut dispatched_sig);
+        (forwarded, dispatched_sig, bindings)
+    } else {
+        (Vec::new(), sig.clone(), Vec::new())
+    };
+
+    let build_fn = |wasm: bool| {
+        let versions = args
+            .versions
+            .iter()
+            .filter(|version| wasm || !version.wasm)
+            .collect::<Vec<_>>();
+        let targets = versions
+            .iter()
+            .map(|version| &version.target)
+            .collect::<Vec<_>>();
+        let tokens = versions.iter().map(|version| &version.token);
+        let generic_cfg = if versions.is_empty() {
             None
+        } else {
+            Some(quote! { #[target_cfg(not(any(#(target = #targets),*)))] })
         };
-        let clone_arm = if nightly {
-            Some(quote! { #[clone(target = "aarch64+neon")] })
+        // The portable token doesn't require any CPU features, so it's only checked by the
+        // fallback version, which calls the portable version rather than repeating the body.
+        let portable = if args.portable {
+            let associated = if is_associated(&sig, &block) {
+                Some(quote! { Self:: })
+            } else {
+                None
+            };
+            let impl_trait = sig.inputs.iter().any(
+                |input| matches!(input, FnArg::Typed(arg) if matches!(*arg.ty, Type::ImplTrait(_))),
+            );
+            let turbofish = generic_args(&sig, impl_trait);
+            Some(quote! {
+                #generic_cfg
+                if <generic_simd::arch::portable::Portable as generic_simd::arch::Token>::new().is_some() {
+                    return #associated #portable_name #turbofish(#(#forwarded),*);
+                }
+            })
         } else {
             None
         };
         quote! {
             #[generic_simd::multiversion::multiversion]
-            #[clone(target = "[x86|x86_64]+avx")]
-            #[clone(target = "[x86|x86_64]+sse4.1")]
-            #clone_wasm
-            #clone_arm
-            #[crate_path(path = "generic_simd::multiversion")]
+            #(#[clone(target = #targets)])*
+            #[crate_path(path = "generic_simd::detect")]
             #(#attrs)*
             #vis
-            #sig
+            #dispatched_sig
             {
-                #[target_cfg(target = "[x86|x86_64]+sse4.1")]
-                let #feature = unsafe { <generic_simd::arch::x86::Sse as generic_simd::arch::Token>::new_unchecked() };
-
-                #[target_cfg(target = "[x86|x86_64]+avx")]
-                let #feature = unsafe { <generic_simd::arch::x86::Avx as generic_simd::arch::Token>::new_unchecked() };
+                #(
+                    #[target_cfg(target = #targets)]
+                    let #feature = unsafe { <#tokens as generic_simd::arch::Token>::new_unchecked() };
+                )*
 
-                #[target_cfg(target = "wasm32+simd128")]
-                let #feature = unsafe { <generic_simd::arch::wasm::Simd128 as generic_simd::arch::Token>::new_unchecked() };
+                #portable
 
-                #[target_cfg(target = "[arm|aarch64]+neon")]
-                let #feature = unsafe { <generic_simd::arch::arm::Neon as generic_simd::arch::Token>::new_unchecked() };
+                #(#bindings)*
 
-                #[target_cfg(not(any(
-                    target = "[x86|x86_64]+sse4.1",
-                    target = "[x86|x86_64]+avx",
-                    target = "[arm|aarch64]+neon",
-                    target = "wasm32+simd128",
-                )))]
+                #generic_cfg
                 let #feature = <generic_simd::arch::generic::Generic as generic_simd::arch::Token>::new().unwrap();
 
                 #block
             }
         }
     };
+
+    // Reports the version selected by the dispatcher, checking the clones in the same order.
+    let report_fn = if args.report {
+        let checks = args.versions.iter().map(|version| {
+            let (archs, features) = version.archs_and_features();
+            let name = &version.name;
+            let wasm = if version.wasm {
+                Some(quote! { target_feature = "simd128", })
+            } else {
+                None
+            };
+            quote! {
+                #[cfg(all(any(#(target_arch = #archs),*), #wasm))]
+                {
+                    if generic_simd::detect::are_cpu_features_detected!(#(#features),*) {
+                        return #name;
+                    }
+                }
+            }
+        });
+        let portable = if args.portable {
+            Some(quote! {
+                if <generic_simd::arch::portable::Portable as generic_simd::arch::Token>::new().is_some() {
+                    return "portable";
+                }
+            })
+        } else {
+            None
+        };
+        let report_vis = report_vis.as_ref().unwrap_or(&vis);
+        let doc = format!(
+            "Returns the name of the version of `{}` selected at runtime.",
+            reported
+        );
+        // The dispatcher's selection isn't accessible, so the report repeats the detection.
+        let limitation = format!(
+            "The version is detected on each call, so if `generic_simd::arch::set_max_token` is \
+             called after the first call to `{}`, this may differ from the version it remembered.",
+            reported
+        );
+        Some(quote! {
+            #[doc = #doc]
+            #[doc = ""]
+            #[doc = #limitation]
+            #[allow(unreachable_code)]
+            #report_vis fn #report_name() -> &'static str {
+                #(#checks)*
+                #portable
+                "generic"
+            }
+        })
+    } else {
+        None
+    };
+
+    // The portable version is named like the `multiversion` clones, so that it can be called
+    // directly, and is called by the fallback version.  The portable token doesn't require any CPU
+    // features, so it's safe to call.
+    // It's multiversioned without any clones so that static dispatch works in its body.
+    let portable_fn = if args.portable {
+        let mut sig = sig.clone();
+        sig.ident = portable_name.clone();
+        Some(quote! {
+            #[generic_simd::multiversion::multiversion]
+            #[crate_path(path = "generic_simd::detect")]
+            #[doc(hidden)]
+            #(#attrs)*
+            #vis
+            #sig
+            {
+                let #feature = generic_simd::arch::portable::Portable::get();
+                #block
+            }
+        })
+    } else {
+        None
+    };
+
     let normal = build_fn(false);
     let with_wasm = build_fn(true);
-    let output = quote! {
+    quote! {
         #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
         #with_wasm
 
         #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128"),))]
         #normal
-    };
-    output.into()
+
+        #portable_fn
+
+        #report_fn
+    }
+}
+
+// Multiversions every method in an impl block.
+fn dispatch_impl(args: &Args, mut item: ItemImpl) -> proc_macro2::TokenStream {
+    let items = std::mem::take(&mut item.items);
+    if item.trait_.is_none() {
+        // Inherent impls can contain the versions of each method directly.
+        for impl_item in items {
+            item.items.push(match impl_item {
+                ImplItem::Method(method) => {
+                    let report_name = report_name(&method.sig.ident);
+                    let reported = method.sig.ident.to_string();
+                    let func = method_to_fn(method, &args.token);
+                    ImplItem::Verbatim(dispatch_fn(args, func, report_name, None, reported))
+                }
+                impl_item => impl_item,
+            });
+        }
+        return quote! { #item };
+    }
+
+    // Trait impls can't contain additional items, so each method forwards to a multiversioned
+    // method in an inherent impl.  Generic parameters that don't appear in the self type can't be
+    // parameters of the inherent impl, so they become parameters of each method instead.
+    let self_ty = &item.self_ty;
+    let self_tokens = quote! { #self_ty };
+    let (kept, moved): (Vec<_>, Vec<_>) = item
+        .generics
+        .params
+        .iter()
+        .cloned()
+        .partition(|param| mentions(&self_tokens, param_ident(param)));
+    let moved_idents = moved.iter().map(param_ident).collect::<Vec<_>>();
+    let (moved_predicates, kept_predicates): (Vec<_>, Vec<_>) = item
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter().cloned())
+        .partition(|predicate| {
+            let predicate = quote! { #predicate };
+            moved_idents.iter().any(|ident| mentions(&predicate, ident))
+        });
+
+    let trait_name = &item
+        .trait_
+        .as_ref()
+        .unwrap()
+        .1
+        .segments
+        .last()
+        .unwrap()
+        .ident;
+    // The inner methods and report functions are prefixed with the trait name, so that methods of
+    // different traits with the same name don't collide.
+    let trait_prefix = snake_case(trait_name);
+    let mut inherent_items = Vec::new();
+    for impl_item in items {
+        item.items.push(match impl_item {
+            ImplItem::Method(method) => {
+                let inner_name = Ident::new(
+                    &format!("__dispatch_{}_{}", trait_prefix, method.sig.ident),
+                    method.sig.ident.span(),
+                );
+                let report_name = report_name(&Ident::new(
+                    &format!("{}_{}", trait_prefix, method.sig.ident),
+                    method.sig.ident.span(),
+                ));
+                let reported = format!("{}::{}", trait_name, method.sig.ident);
+                let mut inner = method_to_fn(method.clone(), &args.token);
+                inner.vis = syn::Visibility::Inherited;
+                inner.sig.ident = inner_name;
+                inner.attrs.push(parse_quote! { #[doc(hidden)] });
+                let mut params = moved.clone();
+                params.extend(inner.sig.generics.params.iter().cloned());
+                // Lifetimes must precede other parameters.
+                params.sort_by_key(|param| !matches!(param, GenericParam::Lifetime(_)));
+                inner.sig.generics.params = params.into_iter().collect();
+                if !moved_predicates.is_empty() {
+                    inner
+                        .sig
+                        .generics
+                        .make_where_clause()
+                        .predicates
+                        .extend(moved_predicates.iter().cloned());
+                }
+                let forward = forward_method(&method, &inner.sig);
+                inherent_items.push(dispatch_fn(
+                    args,
+                    inner,
+                    report_name,
+                    Some(parse_quote! { pub }),
+                    reported,
+                ));
+                ImplItem::Method(forward)
+            }
+            impl_item => impl_item,
+        });
+    }
+    quote! {
+        #item
+
+        impl<#(#kept),*> #self_ty where #(#kept_predicates),* {
+            #(#inherent_items)*
+        }
+    }
+}
+
+fn param_ident(param: &GenericParam) -> &Ident {
+    match param {
+        GenericParam::Type(param) => &param.ident,
+        GenericParam::Lifetime(param) => &param.lifetime.ident,
+        GenericParam::Const(param) => &param.ident,
+    }
+}
+
+// Returns whether the tokens contain the identifier.
+fn mentions(tokens: &proc_macro2::TokenStream, ident: &Ident) -> bool {
+    tokens.clone().into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(token) => token == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions(&group.stream(), ident),
+        _ => false,
+    })
+}
+
+// Returns whether a function is associated, like `multiversion` does: it either has a receiver
+// or mentions `Self`.
+fn is_associated(sig: &Signature, block: &syn::Block) -> bool {
+    sig.receiver().is_some()
+        || mentions(
+            &quote! { #sig #block },
+            &Ident::new("Self", Span::call_site()),
+        )
+}
+
+// Replaces argument patterns that aren't plain identifiers with identifiers.
+//
+// Returns the arguments to forward and the statements that destructure the replaced patterns.
+fn bind_args(
+    sig: &mut Signature,
+) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
+    let mut forwarded = Vec::new();
+    let mut bindings = Vec::new();
+    for (i, input) in sig.inputs.iter_mut().enumerate() {
+        match input {
+            FnArg::Receiver(_) => forwarded.push(quote! { self }),
+            FnArg::Typed(arg) => match &*arg.pat {
+                Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                    let ident = &pat.ident;
+                    forwarded.push(quote! { #ident });
+                }
+                pat => {
+                    let ident = Ident::new(&format!("__dispatch_arg_{}", i), Span::call_site());
+                    bindings.push(quote! { let #pat = #ident; });
+                    forwarded.push(quote! { #ident });
+                    *arg.pat = Pat::Ident(PatIdent {
+                        attrs: Vec::new(),
+                        by_ref: None,
+                        mutability: None,
+                        ident,
+                        subpat: None,
+                    });
+                }
+            },
+        }
+    }
+    (forwarded, bindings)
+}
+
+// Methods in a dispatched impl block may not use the token, so mark it as used.
+fn method_to_fn(method: ImplItemMethod, token: &Ident) -> ItemFn {
+    let block = method.block;
+    ItemFn {
+        attrs: method.attrs,
+        vis: method.vis,
+        sig: method.sig,
+        block: parse_quote! {
+            {
+                let _ = #token;
+                #block
+            }
+        },
+    }
+}
+
+// Replaces the body of a trait method with a call to the inner method.
+fn forward_method(method: &ImplItemMethod, inner: &Signature) -> ImplItemMethod {
+    let inner_name = &inner.ident;
+    let mut sig = method.sig.clone();
+    let mut args = Vec::new();
+    let mut impl_trait = false;
+    for (i, input) in sig.inputs.iter_mut().enumerate() {
+        match input {
+            FnArg::Receiver(_) => args.push(quote! { self }),
+            FnArg::Typed(arg) => {
+                impl_trait |= matches!(*arg.ty, Type::ImplTrait(_));
+                let ident = match &*arg.pat {
+                    Pat::Ident(pat) => pat.ident.clone(),
+                    _ => Ident::new(&format!("__dispatch_arg_{}", i), inner_name.span()),
+                };
+                *arg.pat = Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: ident.clone(),
+                    subpat: None,
+                });
+                args.push(quote! { #ident });
+            }
+        }
+    }
+    let turbofish = generic_args(inner, impl_trait);
+    ImplItemMethod {
+        attrs: method.attrs.clone(),
+        vis: method.vis.clone(),
+        defaultness: method.defaultness,
+        sig,
+        block: parse_quote! {
+            {
+                Self::#inner_name #turbofish(#(#args),*)
+            }
+        },
+    }
+}
+
+// Explicit type and const arguments, which can't be specified when `impl Trait` is an argument.
+fn generic_args(sig: &Signature, impl_trait: bool) -> Option<proc_macro2::TokenStream> {
+    let params = sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(&param.ident),
+            GenericParam::Const(param) => Some(&param.ident),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if params.is_empty() || impl_trait {
+        None
+    } else {
+        Some(quote! { ::<#(#params),*> })
+    }
 }
@@ -0,0 +1,173 @@
+use generic_simd::{arch::AnyToken, dispatch};
+
+#[cfg(test)]
+use generic_simd::{
+    arch::{generic::Generic, supported_tokens, Dispatch, StaticToken, Token, MAX_TOKEN_VAR},
+    scalar::Scalar,
+    vector::{width, Signed, Vector, VectorOf},
+};
+
+// Scales and offsets each element of a slice in place, using vectors of 4 lanes.
+#[cfg(test)]
+struct ScaleOffset<'a> {
+    data: &'a mut [f64],
+    scale: f64,
+    offset: f64,
+}
+
+#[cfg(test)]
+impl<T> Dispatch<T> for ScaleOffset<'_>
+where
+    T: Token,
+    f64: Scalar<T, width::W4>,
+    VectorOf<f64, width::W4, T>: Signed,
+{
+    type Output = usize;
+
+    #[inline]
+    fn call(self, token: T) -> usize {
+        let scale = VectorOf::<f64, width::W4, T>::splat(token, self.scale);
+        let offset = VectorOf::<f64, width::W4, T>::splat(token, self.offset);
+        let mut chunks = self.data.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            let v = VectorOf::<f64, width::W4, T>::read(token, chunk);
+            (v * scale + offset).write(chunk);
+        }
+        for x in chunks.into_remainder() {
+            *x = *x * self.scale + self.offset;
+        }
+        self.data.len()
+    }
+}
+
+#[cfg(test)]
+fn check(token: AnyToken) {
+    let mut data = (0..11).map(|x| x as f64).collect::<Vec<_>>();
+    let len = token.dispatch(ScaleOffset {
+        data: &mut data,
+        scale: 2.,
+        offset: 1.,
+    });
+    assert_eq!(len, 11);
+    for (i, x) in data.iter().enumerate() {
+        assert_eq!(*x, (2 * i + 1) as f64);
+    }
+}
+
+#[test]
+fn any_token_new() {
+    let token = AnyToken::new();
+    assert_eq!(format!("{:?}", token), format!("{:?}", AnyToken::new()));
+    check(token);
+}
+
+#[test]
+fn any_token_generic() {
+    check(AnyToken::Generic(Generic));
+}
+
+#[test]
+fn static_token() {
+    assert!(StaticToken::new().is_some());
+    check(AnyToken::from(StaticToken::get()));
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn any_token_sse() {
+    if let Some(token) = generic_simd::arch::x86::Sse::new() {
+        check(AnyToken::Sse(token));
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn any_token_sse2() {
+    if let Some(token) = generic_simd::arch::x86::Sse2::new() {
+        check(AnyToken::Sse2(token));
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn sse2_get() {
+    check(AnyToken::from(generic_simd::arch::x86::Sse2::get()));
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn any_token_avx() {
+    if let Some(token) = generic_simd::arch::x86::Avx::new() {
+        check(AnyToken::Avx(token));
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn any_token_x86v2() {
+    if let Some(token) = generic_simd::arch::x86::X86V2::new() {
+        check(AnyToken::X86V2(token));
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn any_token_x86v3() {
+    if let Some(token) = generic_simd::arch::x86::X86V3::new() {
+        check(AnyToken::X86V3(token));
+    }
+}
+
+#[dispatch(token, report)]
+pub fn token_name() -> String {
+    AnyToken::from(token).name().to_string()
+}
+
+#[test]
+fn selected_version() {
+    let best = AnyToken::new().name();
+    assert_eq!(token_name_selected_version(), best);
+    assert_eq!(token_name(), best);
+}
+
+#[test]
+fn supported() {
+    let names = supported_tokens().map(AnyToken::name).collect::<Vec<_>>();
+    assert_eq!(names.first(), Some(&AnyToken::new().name()));
+    assert_eq!(names.last(), Some(&"generic"));
+    for token in supported_tokens() {
+        check(token);
+    }
+}
+
+// The environment variable is only read once per process, so the limit is checked in a child
+// process running the ignored `max_token_env_child` test.
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn max_token_env() {
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .arg("any_token::max_token_env_child")
+        .arg("--exact")
+        .arg("--ignored")
+        .env(MAX_TOKEN_VAR, "Sse2")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("1 passed"), "{}", stdout);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+#[ignore]
+fn max_token_env_child() {
+    let names = supported_tokens().map(AnyToken::name).collect::<Vec<_>>();
+    assert_eq!(names.first(), Some(&"sse2"));
+    assert_eq!(names.last(), Some(&"generic"));
+    assert!(generic_simd::arch::x86::Sse::new().is_none());
+    assert_eq!(AnyToken::new().name(), "sse2");
+    assert_eq!(token_name(), "sse2");
+    for token in supported_tokens() {
+        check(token);
+    }
+}
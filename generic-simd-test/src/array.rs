@@ -0,0 +1,69 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Vector, VectorArray, VectorOf},
+};
+
+#[cfg(test)]
+use generic_simd::{arch::generic::Generic, vector::NativeVector};
+
+#[inline]
+fn array_impl<T, W>(token: T)
+where
+    T: Token,
+    W: width::Width + width::ArrayOf<f32>,
+    f32: Scalar<T, W>,
+    VectorArray<VectorOf<f32, W, T>>: AsRef<[f32]> + From<VectorOf<f32, W, T>>,
+{
+    let v = VectorOf::<f32, W, T>::from_fn(token, |i| i as f32 + 1.);
+    for i in 0..W::VALUE {
+        assert_eq!(v.as_slice()[i], i as f32 + 1.);
+    }
+
+    let array = v.to_array();
+    assert_eq!(array.as_ref(), v.as_slice());
+    assert_eq!(
+        VectorOf::<f32, W, T>::from_array(token, array).as_slice(),
+        v.as_slice()
+    );
+    assert_eq!(
+        VectorArray::<VectorOf<f32, W, T>>::from(v).as_ref(),
+        v.as_slice()
+    );
+
+    assert_eq!(v.extract::<0>(), 1.);
+    let replaced = v.replace::<0>(-1.);
+    assert_eq!(replaced.extract::<0>(), -1.);
+    assert_eq!(replaced.as_slice()[1..], v.as_slice()[1..]);
+}
+
+#[dispatch(token)]
+pub fn array() {
+    array_impl::<_, width::W1>(token);
+    array_impl::<_, width::W2>(token);
+    array_impl::<_, width::W4>(token);
+    array_impl::<_, width::W8>(token);
+    array_impl::<_, width::W16>(token);
+    array_impl::<_, width::W32>(token);
+}
+
+dispatch_test! { array }
+
+#[test]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn array_from_into() {
+    let v: VectorOf<f64, width::W4, Generic> = [1., 2., 3., 4.].into();
+    assert_eq!(v.extract::<3>(), 4.);
+    let array: [f64; 4] = v.into();
+    assert_eq!(array, [1., 2., 3., 4.]);
+}
+
+#[test]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn generic_native_arrays() {
+    let v: NativeVector<f32, Generic> = [1., 2., 3., 4.].into();
+    assert_eq!((v * v).to_array(), [1., 4., 9., 16.]);
+    let v: NativeVector<f64, Generic> = [1., 2.].into();
+    assert_eq!((-v).to_array(), [-1., -2.]);
+}
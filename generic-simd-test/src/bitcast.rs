@@ -0,0 +1,101 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Bitwise, Ops, Shift, Vector, VectorOf},
+};
+
+const FLOATS: [f64; 6] = [0., -0., 1.5, -1e300, f64::INFINITY, f64::NAN];
+
+#[inline]
+fn bitcast_impl<T, W>(token: T)
+where
+    T: Token,
+    W: width::Width,
+    f32: Scalar<T, W>,
+    f64: Scalar<T, W>,
+    i32: Scalar<T, W>,
+    u32: Scalar<T, W>,
+    u64: Scalar<T, W>,
+{
+    for offset in 0..FLOATS.len() {
+        let mut f = f32::zeroed(token);
+        let mut d = f64::zeroed(token);
+        for i in 0..W::VALUE {
+            let x = FLOATS[(i + offset) % FLOATS.len()];
+            f.as_slice_mut()[i] = x as f32;
+            d.as_slice_mut()[i] = x;
+        }
+
+        let f_bits: VectorOf<u32, W, T> = f.bitcast();
+        let d_bits: VectorOf<u64, W, T> = d.bitcast();
+        let f_signed: VectorOf<i32, W, T> = f_bits.bitcast();
+        for i in 0..W::VALUE {
+            assert_eq!(f_bits.as_slice()[i], f.as_slice()[i].to_bits());
+            assert_eq!(d_bits.as_slice()[i], d.as_slice()[i].to_bits());
+            assert_eq!(f_signed.as_slice()[i], f.as_slice()[i].to_bits() as i32);
+        }
+
+        let f_back: VectorOf<f32, W, T> = f_signed.bitcast();
+        let d_back: VectorOf<f64, W, T> = d_bits.bitcast();
+        for i in 0..W::VALUE {
+            assert_eq!(f_back.as_slice()[i].to_bits(), f.as_slice()[i].to_bits());
+            assert_eq!(d_back.as_slice()[i].to_bits(), d.as_slice()[i].to_bits());
+        }
+    }
+}
+
+// Extracts the unbiased exponent and the absolute value of normal `f32`s with bit operations.
+#[inline]
+fn exponent_impl<T, W>(token: T)
+where
+    T: Token,
+    W: width::Width,
+    f32: Scalar<T, W>,
+    i32: Scalar<T, W>,
+    VectorOf<f32, W, T>: Bitwise,
+    VectorOf<i32, W, T>: Bitwise + Ops + Shift,
+{
+    let values = [
+        1.,
+        -1.,
+        0.75,
+        3.,
+        -1e-20,
+        6.5e20,
+        f32::MAX,
+        -f32::MIN_POSITIVE,
+    ];
+    for offset in 0..values.len() {
+        let x = VectorOf::<f32, W, T>::from_fn(token, |i| values[(i + offset) % values.len()]);
+        let bits: VectorOf<i32, W, T> = x.bitcast();
+        let exponent = ((bits >> 23) & 0xff) - 127;
+        let abs = x.and_not(f32::splat(-0., token));
+        for i in 0..W::VALUE {
+            let lane = x.as_slice()[i];
+            assert_eq!(
+                exponent.as_slice()[i],
+                f64::from(lane.abs()).log2().floor() as i32
+            );
+            assert_eq!(abs.as_slice()[i], lane.abs());
+        }
+    }
+}
+
+#[dispatch(token)]
+pub fn bitcast() {
+    bitcast_impl::<_, width::W1>(token);
+    bitcast_impl::<_, width::W2>(token);
+    bitcast_impl::<_, width::W4>(token);
+    bitcast_impl::<_, width::W8>(token);
+    bitcast_impl::<_, width::W16>(token);
+    bitcast_impl::<_, width::W32>(token);
+    exponent_impl::<_, width::W1>(token);
+    exponent_impl::<_, width::W2>(token);
+    exponent_impl::<_, width::W4>(token);
+    exponent_impl::<_, width::W8>(token);
+    exponent_impl::<_, width::W16>(token);
+    exponent_impl::<_, width::W32>(token);
+}
+
+dispatch_test! { bitcast }
@@ -0,0 +1,204 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Bitwise, Shift, Vector, VectorOf},
+};
+
+// Converts scalars to and from their bit representation, which is the scalar itself for integers.
+trait Bits: Copy {
+    type Bits;
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+macro_rules! bits {
+    { $scalar:ty, $bits:ty, $to_bits:expr, $from_bits:expr } => {
+        impl Bits for $scalar {
+            type Bits = $bits;
+
+            fn to_bits(self) -> $bits {
+                $to_bits(self)
+            }
+
+            fn from_bits(bits: $bits) -> Self {
+                $from_bits(bits)
+            }
+        }
+    }
+}
+
+bits! { f32, u32, f32::to_bits, f32::from_bits }
+bits! { f64, u64, f64::to_bits, f64::from_bits }
+bits! { i32, i32, |x| x, |x| x }
+bits! { u32, u32, |x| x, |x| x }
+bits! { u64, u64, |x| x, |x| x }
+
+const BITS: [u64; 6] = [
+    0,
+    u64::MAX,
+    0x8000_0000_8000_0000,
+    0x7fff_ffff_7fff_ffff,
+    0x0123_4567_89ab_cdef,
+    0xfedc_ba98_7654_3210,
+];
+
+macro_rules! bitwise_test {
+    { $name:ident, $scalar:ty, $bits:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>,
+            VectorOf<$scalar, W, T>: Bitwise,
+        {
+            let check = |a: VectorOf<$scalar, W, T>,
+                         b: VectorOf<$scalar, W, T>,
+                         scalar: $scalar| {
+                let and = a & b;
+                let or = a | b;
+                let xor = a ^ b;
+                let not = !a;
+                let and_not = a.and_not(b);
+                let and_scalar = a & scalar;
+                let mut xor_assign = a;
+                xor_assign ^= b;
+                for i in 0..W::VALUE {
+                    let (x, y) = (a.as_slice()[i].to_bits(), b.as_slice()[i].to_bits());
+                    assert_eq!(and.as_slice()[i].to_bits(), x & y);
+                    assert_eq!(or.as_slice()[i].to_bits(), x | y);
+                    assert_eq!(xor.as_slice()[i].to_bits(), x ^ y);
+                    assert_eq!(not.as_slice()[i].to_bits(), !x);
+                    assert_eq!(and_not.as_slice()[i].to_bits(), x & !y);
+                    assert_eq!(and_scalar.as_slice()[i].to_bits(), x & scalar.to_bits());
+                    assert_eq!(xor_assign.as_slice()[i].to_bits(), x ^ y);
+                }
+            };
+
+            for offset in 0..BITS.len() {
+                let mut a = <$scalar>::zeroed(token);
+                let mut b = <$scalar>::zeroed(token);
+                for i in 0..W::VALUE {
+                    a.as_slice_mut()[i] = <$scalar>::from_bits(BITS[(i + offset) % BITS.len()] as $bits);
+                    b.as_slice_mut()[i] = <$scalar>::from_bits(BITS[(i * 5 + 1) % BITS.len()] as $bits);
+                }
+                check(a, b, <$scalar>::from_bits(BITS[offset] as $bits));
+            }
+        }
+    }
+}
+
+bitwise_test! { bitwise_f32, f32, u32 }
+bitwise_test! { bitwise_f64, f64, u64 }
+bitwise_test! { bitwise_i32, i32, i32 }
+bitwise_test! { bitwise_u32, u32, u32 }
+bitwise_test! { bitwise_u64, u64, u64 }
+
+macro_rules! shift_test {
+    { $name:ident, $scalar:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>,
+            VectorOf<$scalar, W, T>: Shift,
+        {
+            const LANE_BITS: u32 = (core::mem::size_of::<$scalar>() * 8) as u32;
+            let mut a = <$scalar>::zeroed(token);
+            for i in 0..W::VALUE {
+                a.as_slice_mut()[i] = BITS[i % BITS.len()] as $scalar;
+            }
+            for &shift in [0, 1, 7, LANE_BITS - 1, LANE_BITS, LANE_BITS + 7, 1000].iter() {
+                let shl = a << shift;
+                let shr = a >> shift;
+                let mut shl_assign = a;
+                shl_assign <<= shift;
+                let mut shr_assign = a;
+                shr_assign >>= shift;
+                for i in 0..W::VALUE {
+                    let x = a.as_slice()[i];
+                    let expected_shl = x.checked_shl(shift).unwrap_or(0);
+                    let expected_shr = x
+                        .checked_shr(shift)
+                        .unwrap_or(x >> (LANE_BITS - 1) >> 1);
+                    assert_eq!(shl.as_slice()[i], expected_shl);
+                    assert_eq!(shr.as_slice()[i], expected_shr);
+                    assert_eq!(shl_assign.as_slice()[i], expected_shl);
+                    assert_eq!(shr_assign.as_slice()[i], expected_shr);
+                }
+            }
+        }
+    }
+}
+
+shift_test! { shift_i32, i32 }
+shift_test! { shift_u32, u32 }
+shift_test! { shift_u64, u64 }
+
+#[dispatch(token)]
+pub fn bitwise() {
+    bitwise_f32::<_, width::W1>(token);
+    bitwise_f32::<_, width::W2>(token);
+    bitwise_f32::<_, width::W4>(token);
+    bitwise_f32::<_, width::W8>(token);
+    bitwise_f32::<_, width::W16>(token);
+    bitwise_f32::<_, width::W32>(token);
+    bitwise_f64::<_, width::W1>(token);
+    bitwise_f64::<_, width::W2>(token);
+    bitwise_f64::<_, width::W4>(token);
+    bitwise_f64::<_, width::W8>(token);
+    bitwise_f64::<_, width::W16>(token);
+    bitwise_f64::<_, width::W32>(token);
+    bitwise_i32::<_, width::W1>(token);
+    bitwise_i32::<_, width::W2>(token);
+    bitwise_i32::<_, width::W4>(token);
+    bitwise_i32::<_, width::W8>(token);
+    bitwise_i32::<_, width::W16>(token);
+    bitwise_i32::<_, width::W32>(token);
+    bitwise_u32::<_, width::W1>(token);
+    bitwise_u32::<_, width::W2>(token);
+    bitwise_u32::<_, width::W4>(token);
+    bitwise_u32::<_, width::W8>(token);
+    bitwise_u32::<_, width::W16>(token);
+    bitwise_u32::<_, width::W32>(token);
+    bitwise_u64::<_, width::W1>(token);
+    bitwise_u64::<_, width::W2>(token);
+    bitwise_u64::<_, width::W4>(token);
+    bitwise_u64::<_, width::W8>(token);
+    bitwise_u64::<_, width::W16>(token);
+    bitwise_u64::<_, width::W32>(token);
+    shift_i32::<_, width::W1>(token);
+    shift_i32::<_, width::W2>(token);
+    shift_i32::<_, width::W4>(token);
+    shift_i32::<_, width::W8>(token);
+    shift_i32::<_, width::W16>(token);
+    shift_i32::<_, width::W32>(token);
+    shift_u32::<_, width::W1>(token);
+    shift_u32::<_, width::W2>(token);
+    shift_u32::<_, width::W4>(token);
+    shift_u32::<_, width::W8>(token);
+    shift_u32::<_, width::W16>(token);
+    shift_u32::<_, width::W32>(token);
+    shift_u64::<_, width::W1>(token);
+    shift_u64::<_, width::W2>(token);
+    shift_u64::<_, width::W4>(token);
+    shift_u64::<_, width::W8>(token);
+    shift_u64::<_, width::W16>(token);
+    shift_u64::<_, width::W32>(token);
+}
+
+dispatch_test! { bitwise }
+
+#[test]
+fn bitwise_emulated() {
+    use generic_simd::arch::emulated::Emulated;
+    let token = Emulated::<width::W8>::get();
+    bitwise_f64::<_, width::W16>(token);
+    bitwise_i32::<_, width::W4>(token);
+    bitwise_u64::<_, width::W8>(token);
+    shift_i32::<_, width::W8>(token);
+    shift_u32::<_, width::W16>(token);
+    shift_u64::<_, width::W2>(token);
+}
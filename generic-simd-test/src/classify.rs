@@ -0,0 +1,102 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    slice::SliceExt,
+    vector::{width, Classify, Vector, VectorOf},
+};
+
+macro_rules! classify_test {
+    { $name:ident, $scalar:ident } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width + width::ArrayOf<bool>,
+            $scalar: Scalar<T, W>,
+            VectorOf<$scalar, W, T>: Classify,
+        {
+            let values = [
+                0.,
+                -0.,
+                1.,
+                -1.,
+                $scalar::MIN_POSITIVE,
+                $scalar::MIN_POSITIVE / 2.,
+                -$scalar::MIN_POSITIVE / 2.,
+                $scalar::MAX,
+                $scalar::INFINITY,
+                $scalar::NEG_INFINITY,
+                $scalar::NAN,
+                -$scalar::NAN,
+            ];
+            for offset in 0..values.len() {
+                let v = VectorOf::<$scalar, W, T>::from_fn(token, |i| {
+                    values[(offset + i) % values.len()]
+                });
+                let nan = v.is_nan();
+                let infinite = v.is_infinite();
+                let finite = v.is_finite();
+                let subnormal = v.is_subnormal();
+                let sign_negative = v.is_sign_negative();
+                for (i, x) in v.as_slice().iter().enumerate() {
+                    assert_eq!(nan.as_ref()[i], x.is_nan());
+                    assert_eq!(infinite.as_ref()[i], x.is_infinite());
+                    assert_eq!(finite.as_ref()[i], x.is_finite());
+                    assert_eq!(
+                        subnormal.as_ref()[i],
+                        x.classify() == core::num::FpCategory::Subnormal
+                    );
+                    assert_eq!(sign_negative.as_ref()[i], x.is_sign_negative());
+                }
+            }
+        }
+    }
+}
+
+classify_test! { classify_f32, f32 }
+classify_test! { classify_f64, f64 }
+
+#[dispatch(token)]
+pub fn classify() {
+    classify_f32::<_, width::W1>(token);
+    classify_f32::<_, width::W2>(token);
+    classify_f32::<_, width::W4>(token);
+    classify_f32::<_, width::W8>(token);
+    classify_f32::<_, width::W16>(token);
+    classify_f32::<_, width::W32>(token);
+    classify_f64::<_, width::W1>(token);
+    classify_f64::<_, width::W2>(token);
+    classify_f64::<_, width::W4>(token);
+    classify_f64::<_, width::W8>(token);
+    classify_f64::<_, width::W16>(token);
+    classify_f64::<_, width::W32>(token);
+}
+
+dispatch_test! { classify }
+
+#[dispatch(token)]
+pub fn classify_slice() {
+    let mut buffer = [1f32; 37];
+    for offset in 0..4 {
+        let slice = &mut buffer[offset..];
+        assert!(!slice.any_non_finite_native(token));
+        assert_eq!(slice.first_non_finite_native(token), None);
+        for (index, value) in [(0, f32::NAN), (5, f32::INFINITY), (20, f32::NEG_INFINITY)]
+            .iter()
+            .copied()
+        {
+            for special in slice.len().saturating_sub(2)..slice.len() {
+                slice[special] = f32::NAN;
+                slice[index] = value;
+                assert!(slice.any_non_finite_native(token));
+                assert_eq!(slice.first_non_finite_native(token), Some(index));
+                slice[index] = 1.;
+                assert_eq!(slice.first_non_finite_native(token), Some(special));
+                slice[special] = 1.;
+            }
+        }
+    }
+}
+
+dispatch_test! { classify_slice }
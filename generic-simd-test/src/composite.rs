@@ -0,0 +1,70 @@
+#[cfg(test)]
+use generic_simd::arch::{generic::Generic, Token};
+
+#[test]
+fn generic_pair() {
+    assert!(<(Generic, Generic)>::new().is_some());
+    assert!(<(Generic, Generic, Generic)>::new().is_some());
+}
+
+#[cfg(all(test, any(target_arch = "x86", target_arch = "x86_64")))]
+mod x86 {
+    use generic_simd::{
+        arch::{
+            generic::Generic,
+            x86::{Avx, F16c, Fma, Sse, X86V2, X86V3},
+            Token,
+        },
+        scalar::ScalarExt,
+    };
+
+    // Requires both AVX vectors and FMA through a single token.
+    fn fused<T: Token + Into<Avx> + Into<Fma>>(token: T) -> f32 {
+        let avx: Avx = token.into();
+        let _: Fma = token.into();
+        let x = 2f32.splat_native(avx);
+        (x * x).iter().sum()
+    }
+
+    #[test]
+    fn pair() {
+        let expected = Avx::new().is_some() && Fma::new().is_some();
+        assert_eq!(<(Avx, Fma)>::new().is_some(), expected);
+        assert_eq!(<(Fma, Avx)>::new().is_some(), expected);
+        if let Some(token) = <(Avx, Fma)>::new() {
+            assert_eq!(fused(token), 32.);
+            let _: Generic = token.into();
+        }
+    }
+
+    #[test]
+    fn triple() {
+        let expected = Avx::new().is_some() && Fma::new().is_some() && F16c::new().is_some();
+        assert_eq!(<(Avx, Fma, F16c)>::new().is_some(), expected);
+        if let Some(token) = <(F16c, Avx, Fma)>::new() {
+            assert_eq!(fused(token), 32.);
+            let _: F16c = token.into();
+        }
+    }
+
+    #[test]
+    fn level() {
+        let expected = X86V2::new().is_some() && Avx::new().is_some() && Fma::new().is_some();
+        assert_eq!(<(X86V2, Avx, Fma)>::new().is_some(), expected);
+        if let Some(token) = <(X86V2, Avx, Fma)>::new() {
+            assert_eq!(fused(token), 32.);
+            let _: X86V2 = token.into();
+        }
+        if let Some(token) = <(X86V3, F16c)>::new() {
+            let _: X86V3 = token.into();
+            let _: F16c = token.into();
+        }
+    }
+
+    #[test]
+    fn unsupported_component() {
+        if Sse::new().is_none() {
+            assert!(<(Sse, Generic)>::new().is_none());
+        }
+    }
+}
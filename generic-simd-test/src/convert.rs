@@ -0,0 +1,136 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Convert, ConvertToInt, Vector, VectorOf},
+};
+
+// Input, truncated, rounded, and saturated values.
+// Truncation and rounding are unspecified for NaN and out of range values.
+#[allow(clippy::type_complexity)]
+const FLOATS: [(f64, Option<i32>, Option<i32>, i32); 10] = [
+    (0.5, Some(0), Some(0), 0),
+    (1.5, Some(1), Some(2), 1),
+    (-2.5, Some(-2), Some(-2), -2),
+    (2.75, Some(2), Some(3), 2),
+    (-3.5, Some(-3), Some(-4), -3),
+    (-0.25, Some(0), Some(0), 0),
+    (1e10, None, None, 2147483647),
+    (-1e10, None, None, -2147483648),
+    (f64::INFINITY, None, None, 2147483647),
+    (f64::NAN, None, None, 0),
+];
+
+const INTS: [i32; 6] = [0, 1, -7, 16777217, 2147483647, -2147483648];
+
+fn lanes<V: Vector>(token: V::Token, values: &[V::Scalar], offset: usize) -> V {
+    let mut v = V::zeroed(token);
+    for (i, x) in v.as_slice_mut().iter_mut().enumerate() {
+        *x = values[(i + offset) % values.len()];
+    }
+    v
+}
+
+#[inline]
+fn convert_impl<T, W>(token: T)
+where
+    T: Token,
+    W: width::Width,
+    f32: Scalar<T, W>,
+    f64: Scalar<T, W>,
+    i32: Scalar<T, W>,
+    VectorOf<f32, W, T>: Convert<VectorOf<f64, W, T>> + ConvertToInt<VectorOf<i32, W, T>>,
+    VectorOf<f64, W, T>: Convert<VectorOf<f32, W, T>> + ConvertToInt<VectorOf<i32, W, T>>,
+    VectorOf<i32, W, T>: Convert<VectorOf<f32, W, T>> + Convert<VectorOf<f64, W, T>>,
+{
+    let doubles = FLOATS.iter().map(|x| x.0).collect::<Vec<_>>();
+    let floats = FLOATS.iter().map(|x| x.0 as f32).collect::<Vec<_>>();
+    for offset in 0..FLOATS.len() {
+        let f = lanes::<VectorOf<f32, W, T>>(token, &floats, offset);
+        let d = lanes::<VectorOf<f64, W, T>>(token, &doubles, offset);
+
+        let f_to_d: VectorOf<f64, W, T> = f.convert(token);
+        let d_to_f: VectorOf<f32, W, T> = d.convert(token);
+        for i in 0..W::VALUE {
+            assert_eq!(
+                f_to_d.as_slice()[i].to_bits(),
+                (f.as_slice()[i] as f64).to_bits()
+            );
+            assert_eq!(
+                d_to_f.as_slice()[i].to_bits(),
+                (d.as_slice()[i] as f32).to_bits()
+            );
+        }
+
+        let (f_truncate, f_round, f_saturate): (VectorOf<i32, W, T>, _, _) = (
+            f.convert_truncate(token),
+            f.convert_round(token),
+            f.convert_saturate(token),
+        );
+        let (d_truncate, d_round, d_saturate): (VectorOf<i32, W, T>, _, _) = (
+            d.convert_truncate(token),
+            d.convert_round(token),
+            d.convert_saturate(token),
+        );
+        for i in 0..W::VALUE {
+            let (_, truncate, round, saturate) = FLOATS[(i + offset) % FLOATS.len()];
+            if let Some(truncate) = truncate {
+                assert_eq!(f_truncate.as_slice()[i], truncate);
+                assert_eq!(d_truncate.as_slice()[i], truncate);
+            }
+            if let Some(round) = round {
+                assert_eq!(f_round.as_slice()[i], round);
+                assert_eq!(d_round.as_slice()[i], round);
+            }
+            assert_eq!(f_saturate.as_slice()[i], saturate);
+            assert_eq!(d_saturate.as_slice()[i], saturate);
+        }
+    }
+
+    for offset in 0..INTS.len() {
+        let x = lanes::<VectorOf<i32, W, T>>(token, &INTS, offset);
+        let f: VectorOf<f32, W, T> = x.convert(token);
+        let d: VectorOf<f64, W, T> = x.convert(token);
+        for i in 0..W::VALUE {
+            assert_eq!(f.as_slice()[i], x.as_slice()[i] as f32);
+            assert_eq!(d.as_slice()[i], x.as_slice()[i] as f64);
+        }
+    }
+}
+
+#[dispatch(token)]
+pub fn convert() {
+    convert_impl::<_, width::W1>(token);
+    convert_impl::<_, width::W2>(token);
+    convert_impl::<_, width::W4>(token);
+    convert_impl::<_, width::W8>(token);
+    convert_impl::<_, width::W16>(token);
+    convert_impl::<_, width::W32>(token);
+}
+
+dispatch_test! { convert }
+
+#[inline]
+fn convert_split_impl<T>(token: T)
+where
+    T: Token,
+    f32: Scalar<T, width::W4>,
+    f64: Scalar<T, width::W2> + Scalar<T, width::W4>,
+    VectorOf<f32, width::W4, T>: Convert<VectorOf<f64, width::W4, T>>,
+{
+    let v = <f32 as Scalar<T, width::W4>>::splat(1.5, token);
+    let (low, high): (VectorOf<f64, width::W2, T>, VectorOf<f64, width::W2, T>) =
+        Convert::<VectorOf<f64, width::W4, T>>::convert_split(v, token);
+    assert!(low
+        .as_slice()
+        .iter()
+        .chain(high.as_slice())
+        .all(|x| *x == 1.5));
+}
+
+#[dispatch(token)]
+pub fn convert_split() {
+    convert_split_impl(token);
+}
+
+dispatch_test! { convert_split }
@@ -0,0 +1,67 @@
+use generic_simd::{arch::AnyToken, dispatch};
+
+#[cfg(test)]
+use generic_simd::arch::{supported_tokens, Token};
+
+#[dispatch(token, report, tokens = [Sse, Generic])]
+pub fn sse_only() -> String {
+    AnyToken::from(token).name().to_string()
+}
+
+#[dispatch(token, report, exclude = [Avx])]
+pub fn without_avx() -> String {
+    AnyToken::from(token).name().to_string()
+}
+
+#[dispatch(token, report, tokens = [Generic])]
+pub fn generic_only() -> String {
+    AnyToken::from(token).name().to_string()
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[dispatch(token, report, tokens = [generic_simd::arch::x86::Avx = "[x86|x86_64]+avx+fma"])]
+pub fn avx_fma() -> String {
+    AnyToken::from(token).name().to_string()
+}
+
+#[test]
+fn tokens() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    let expected = if generic_simd::arch::x86::Sse::new().is_some() {
+        "sse"
+    } else {
+        "generic"
+    };
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    let expected = "generic";
+    assert_eq!(sse_only(), expected);
+    assert_eq!(sse_only_selected_version(), expected);
+}
+
+#[test]
+fn exclude() {
+    let expected = supported_tokens()
+        .map(AnyToken::name)
+        .find(|name| *name != "avx")
+        .unwrap();
+    assert_eq!(without_avx(), expected);
+    assert_eq!(without_avx_selected_version(), expected);
+}
+
+#[test]
+fn generic() {
+    assert_eq!(generic_only(), "generic");
+    assert_eq!(generic_only_selected_version(), "generic");
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn custom_target() {
+    let expected = if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+        "avx"
+    } else {
+        "generic"
+    };
+    assert_eq!(avx_fma(), expected);
+    assert_eq!(avx_fma_selected_version(), expected);
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+use generic_simd::{
+    arch::{emulated::Emulated, Token},
+    scalar::ScalarExt,
+    slice::SliceExt,
+    vector::{width, ConvertToInt, Horizontal, NativeVector, NativeWidth, Vector},
+};
+
+// Sums a slice one native vector at a time, handling the unaligned ends separately.
+#[cfg(test)]
+fn sum<T>(token: T, input: &[f64]) -> ((usize, usize, usize), f64)
+where
+    T: Token,
+    f64: ScalarExt<T>,
+    NativeVector<f64, T>: Horizontal,
+{
+    let (start, vectors, end) = input.align_native(token);
+    let mut total = f64::zeroed_native(token);
+    for v in vectors {
+        total += *v;
+    }
+    let sum = start.iter().chain(end).sum::<f64>() + total.horizontal_sum();
+    ((start.len(), vectors.len(), end.len()), sum)
+}
+
+// The lengths of the start, vectors and end of an aligned slice.
+#[cfg(test)]
+fn expected_lengths<W: width::Width>(input: &[f64]) -> (usize, usize, usize)
+where
+    Emulated<W>: Token,
+    f64: ScalarExt<Emulated<W>>,
+{
+    let offset = input
+        .as_ptr()
+        .align_offset(core::mem::align_of::<NativeVector<f64, Emulated<W>>>());
+    let start = offset.min(input.len());
+    let vectors = (input.len() - start) / W::VALUE;
+    (start, vectors, input.len() - start - vectors * W::VALUE)
+}
+
+#[cfg(test)]
+fn check<W: width::Width>()
+where
+    Emulated<W>: Token,
+    f32: ScalarExt<Emulated<W>>,
+    f64: ScalarExt<Emulated<W>>,
+    NativeVector<f64, Emulated<W>>: Horizontal,
+{
+    let token = Emulated::<W>::new().unwrap();
+    assert_eq!(
+        <NativeWidth<f64, Emulated<W>> as width::Width>::VALUE,
+        W::VALUE
+    );
+    assert_eq!(NativeVector::<f64, Emulated<W>>::width(), W::VALUE);
+    assert_eq!(
+        core::mem::align_of::<NativeVector<f64, Emulated<W>>>(),
+        8 * W::VALUE
+    );
+    assert_eq!(
+        core::mem::align_of::<NativeVector<f32, Emulated<W>>>(),
+        4 * W::VALUE
+    );
+
+    for len in 0..40usize {
+        let input = (0..len).map(|x| x as f64).collect::<Vec<_>>();
+        assert_eq!(
+            sum(token, &input),
+            (
+                expected_lengths::<W>(&input),
+                (len * len.saturating_sub(1) / 2) as f64
+            )
+        );
+        if len > 0 {
+            let unaligned = &input[1..];
+            assert_eq!(
+                sum(token, unaligned),
+                (
+                    expected_lengths::<W>(unaligned),
+                    (len * len.saturating_sub(1) / 2) as f64
+                )
+            );
+        }
+    }
+
+    // A slice starting one lane into a vector has a partial vector at the start.
+    let buffer = [f64::zeroed_native(token); 4];
+    let scalars =
+        unsafe { core::slice::from_raw_parts(buffer.as_ptr() as *const f64, 4 * W::VALUE) };
+    let (start, vectors, end) = scalars[1..].align_native(token);
+    if W::VALUE > 1 {
+        assert!(!start.is_empty());
+    }
+    assert_eq!(
+        (start.len(), vectors.len(), end.len()),
+        expected_lengths::<W>(&scalars[1..])
+    );
+}
+
+#[test]
+fn emulated_w1() {
+    check::<width::W1>();
+}
+
+#[test]
+fn emulated_w4() {
+    check::<width::W4>();
+}
+
+#[test]
+fn emulated_w8() {
+    check::<width::W8>();
+}
+
+#[test]
+fn emulated_w32() {
+    check::<width::W32>();
+}
+
+#[test]
+fn emulated_ops() {
+    let token = Emulated::<width::W8>::get();
+    let a = 2f32.splat_native(token);
+    let b = 3f32.splat_native(token);
+    let c = (a * b - a) / a;
+    assert_eq!(c.len(), 8);
+    assert!(c.iter().all(|x| *x == 2.));
+    let converted: NativeVector<i32, Emulated<width::W8>> = c.convert_truncate(token);
+    assert!(converted.iter().all(|x| *x == 2));
+    let underlying = c.to_underlying();
+    assert_eq!(
+        NativeVector::<f32, Emulated<width::W8>>::from_underlying(token, underlying).to_array(),
+        [2.; 8]
+    );
+}
@@ -0,0 +1,130 @@
+use generic_simd::{
+    arch::generic,
+    arithmetic_ops, as_slice, bitwise_ops, impl_scalar, impl_token,
+    shim::ShimToken,
+    vector::{width, Classify, Horizontal, PrefixSum, Vector},
+};
+
+#[cfg(test)]
+use generic_simd::{arch::Token, scalar::ScalarExt, vector::NativeVector};
+
+// A token and vector implemented outside of the crate with the public macros.
+#[derive(Copy, Clone, Debug)]
+pub struct Portable(());
+
+impl_token! { unsafe Portable }
+
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f64x2([f64; 2]);
+
+arithmetic_ops! {
+    for f64x2:
+        add -> (),
+        sub -> (),
+        mul -> (),
+        div -> ()
+}
+
+bitwise_ops! {
+    for f64x2:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+as_slice! { f64x2 }
+
+impl core::ops::Neg for f64x2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self([-self.0[0], -self.0[1]])
+    }
+}
+
+impl PrefixSum for f64x2 {}
+impl Horizontal for f64x2 {}
+impl Classify for f64x2 {}
+
+unsafe impl Vector for f64x2 {
+    type Scalar = f64;
+    type Token = Portable;
+    type Width = width::W2;
+    type Underlying = [f64; 2];
+
+    fn zeroed(_: Self::Token) -> Self {
+        Self([0.; 2])
+    }
+
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self([from; 2])
+    }
+}
+
+impl_scalar! {
+    Portable, f64, native 2 {
+        1 => ShimToken<generic::f64x1, Self, Portable>,
+    }
+    2.. => f64x2
+}
+
+// Evaluates a polynomial with the generic interface.
+#[cfg(test)]
+fn polynomial<T>(token: T, x: f64) -> f64
+where
+    T: Token,
+    f64: ScalarExt<T>,
+    NativeVector<f64, T>: Horizontal,
+{
+    let x = x.splat_native(token);
+    let y = x * x * 3. + x * 2. - 1.;
+    (y / x.len() as f64).horizontal_sum()
+}
+
+#[test]
+fn extension_token() {
+    let token = Portable::new().unwrap();
+    assert_eq!(format!("{:?}", Portable::get()), "Portable(())");
+    assert_eq!(
+        polynomial(token, 2.),
+        polynomial(generic::Generic::new().unwrap(), 2.)
+    );
+}
+
+#[test]
+fn extension_ops() {
+    let token = Portable::get();
+    let a = f64x2::from_array(token, [1., -2.]);
+    let b = 4f64.splat_lanes::<2>(token);
+    assert_eq!((a + b).to_array(), [5., 2.]);
+    assert_eq!((-a * 2.).to_array(), [-2., 4.]);
+    assert_eq!((a ^ b ^ b).to_array(), a.to_array());
+    assert_eq!((a & !a).to_array(), [0.; 2]);
+    assert_eq!(vec![a, b, a].into_iter().sum::<f64>(), 6.);
+    assert_eq!(a.to_underlying(), [1., -2.]);
+    assert_eq!(f64x2::from_underlying(token, [3., 4.]).to_array(), [3., 4.]);
+
+    let mut c = 1f64.splat_lanes::<8>(token);
+    c *= 3.;
+    assert!(c.iter().all(|x| *x == 3.));
+}
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod detected {
+    use generic_simd::{arch::Token, impl_token};
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct Baseline(());
+
+    impl_token! { unsafe Baseline => "sse2" }
+
+    #[test]
+    fn extension_detected() {
+        assert!(Baseline::new().is_some());
+        let _ = Baseline::get();
+    }
+}
@@ -0,0 +1,76 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Horizontal, Vector, VectorOf},
+};
+
+macro_rules! horizontal_test {
+    { $name:ident, $scalar:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>,
+            VectorOf<$scalar, W, T>: Horizontal,
+        {
+            let a = VectorOf::<$scalar, W, T>::from_fn(token, |i| (i * 3 + 1) as $scalar);
+            let b = VectorOf::<$scalar, W, T>::from_fn(token, |i| (i + 2) as $scalar);
+
+            let lanes = a.iter().chain(b.iter()).copied().collect::<Vec<_>>();
+            let pairs = a.horizontal_add_pairs(b);
+            for i in 0..W::VALUE {
+                assert_eq!(pairs[i], lanes[2 * i] + lanes[2 * i + 1]);
+            }
+
+            assert_eq!(a.horizontal_sum(), a.iter().copied().sum::<$scalar>());
+            assert_eq!(
+                a.dot(b),
+                a.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<$scalar>()
+            );
+        }
+    }
+}
+
+horizontal_test! { horizontal_f32, f32 }
+horizontal_test! { horizontal_f64, f64 }
+horizontal_test! { horizontal_i32, i32 }
+horizontal_test! { horizontal_u32, u32 }
+horizontal_test! { horizontal_u64, u64 }
+
+#[dispatch(token)]
+pub fn horizontal() {
+    horizontal_f32::<_, width::W1>(token);
+    horizontal_f32::<_, width::W2>(token);
+    horizontal_f32::<_, width::W4>(token);
+    horizontal_f32::<_, width::W8>(token);
+    horizontal_f32::<_, width::W16>(token);
+    horizontal_f32::<_, width::W32>(token);
+    horizontal_f64::<_, width::W1>(token);
+    horizontal_f64::<_, width::W2>(token);
+    horizontal_f64::<_, width::W4>(token);
+    horizontal_f64::<_, width::W8>(token);
+    horizontal_f64::<_, width::W16>(token);
+    horizontal_f64::<_, width::W32>(token);
+    horizontal_i32::<_, width::W1>(token);
+    horizontal_i32::<_, width::W2>(token);
+    horizontal_i32::<_, width::W4>(token);
+    horizontal_i32::<_, width::W8>(token);
+    horizontal_i32::<_, width::W16>(token);
+    horizontal_i32::<_, width::W32>(token);
+    horizontal_u32::<_, width::W1>(token);
+    horizontal_u32::<_, width::W2>(token);
+    horizontal_u32::<_, width::W4>(token);
+    horizontal_u32::<_, width::W8>(token);
+    horizontal_u32::<_, width::W16>(token);
+    horizontal_u32::<_, width::W32>(token);
+    horizontal_u64::<_, width::W1>(token);
+    horizontal_u64::<_, width::W2>(token);
+    horizontal_u64::<_, width::W4>(token);
+    horizontal_u64::<_, width::W8>(token);
+    horizontal_u64::<_, width::W16>(token);
+    horizontal_u64::<_, width::W32>(token);
+}
+
+dispatch_test! { horizontal }
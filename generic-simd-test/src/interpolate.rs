@@ -0,0 +1,65 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Interpolate, Vector, VectorOf},
+};
+
+macro_rules! interpolate_test {
+    { $name:ident, $scalar:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>,
+            VectorOf<$scalar, W, T>: Interpolate,
+        {
+            let a = VectorOf::<$scalar, W, T>::from_fn(token, |i| i as $scalar);
+            let b = VectorOf::<$scalar, W, T>::from_fn(token, |i| (2 * i + 4) as $scalar);
+            let t = VectorOf::<$scalar, W, T>::from_fn(token, |i| (i % 3) as $scalar * 0.5);
+
+            let lerp = a.lerp(b, t);
+            let lerp_scalar = a.lerp(10., 0.25);
+            let inverse = lerp.inverse_lerp(a, b);
+            let remap = a.remap(0., 8., b, 12.);
+            let smoothstep = a.smoothstep(1., 5.);
+            let clamp = a.clamp(1., b - 3.);
+            for i in 0..W::VALUE {
+                let (a, b, t) = (a[i], b[i], t[i]);
+                assert_eq!(lerp[i], a + (b - a) * t);
+                assert_eq!(lerp_scalar[i], a + (10. - a) * 0.25);
+                assert_eq!(inverse[i], t);
+                assert_eq!(remap[i], b + (12. - b) * (a / 8.));
+
+                let x = ((a - 1.) / 4.).max(0.).min(1.);
+                assert_eq!(smoothstep[i], x * x * (3. - 2. * x));
+                assert_eq!(clamp[i], a.max(1.).min(b - 3.));
+            }
+
+            let nan = VectorOf::<$scalar, W, T>::splat(token, <$scalar>::NAN);
+            assert!(nan.clamp(0., 1.).iter().all(|x| x.is_nan()));
+        }
+    }
+}
+
+interpolate_test! { interpolate_f32, f32 }
+interpolate_test! { interpolate_f64, f64 }
+
+#[dispatch(token)]
+pub fn interpolate() {
+    interpolate_f32::<_, width::W1>(token);
+    interpolate_f32::<_, width::W2>(token);
+    interpolate_f32::<_, width::W4>(token);
+    interpolate_f32::<_, width::W8>(token);
+    interpolate_f32::<_, width::W16>(token);
+    interpolate_f32::<_, width::W32>(token);
+    interpolate_f64::<_, width::W1>(token);
+    interpolate_f64::<_, width::W2>(token);
+    interpolate_f64::<_, width::W4>(token);
+    interpolate_f64::<_, width::W8>(token);
+    interpolate_f64::<_, width::W16>(token);
+    interpolate_f64::<_, width::W32>(token);
+}
+
+dispatch_test! { interpolate }
@@ -13,12 +13,115 @@
 
 use generic_simd::{dispatch, scalar::ScalarExt, vector::Signed};
 use num_traits::Num;
-use rand::distributions::Standard;
+use rand::distributions::{Standard, Uniform};
 use rand::prelude::*;
 use rand::SeedableRng;
 
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
+// Tests a function tagged with `#[dispatch]` with every supported token.
+macro_rules! dispatch_test {
+    { $name:ident } => {
+        paste::paste! {
+            #[test]
+            #[wasm_bindgen_test::wasm_bindgen_test]
+            pub fn [<$name _generic>]() {
+                [<$name _default_version>]()
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _sse>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::Sse::new().is_some() {
+                    unsafe { [<$name _sse41_version>]() }
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _sse2>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::Sse2::new().is_some() {
+                    unsafe { [<$name _sse2_version>]() }
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _avx>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::Avx::new().is_some() {
+                    unsafe { [<$name _avx_version>]() }
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _x86v2>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::X86V2::new().is_some() {
+                    unsafe { [<$name _popcnt_sse3_sse41_sse42_ssse3_version>]() }
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _x86v3>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::X86V3::new().is_some() {
+                    unsafe { [<$name _avx2_bmi1_bmi2_f16c_fma_lzcnt_popcnt_version>]() }
+                }
+            }
+
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            #[test]
+            pub fn [<$name _neon>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::arm::Neon::new().is_some() {
+                    unsafe { [<$name _neon_version>]() }
+                }
+            }
+
+            #[cfg(all(feature = "nightly", target_arch = "wasm32", target_feature = "simd128"))]
+            #[wasm_bindgen_test::wasm_bindgen_test]
+            pub fn [<$name _simd128>]() {
+                use generic_simd::arch::Token as _;
+                assert!(generic_simd::arch::wasm::Simd128::new().is_some());
+                unsafe { [<$name _simd128_version>]() }
+            }
+
+            #[cfg(feature = "nightly")]
+            #[test]
+            #[wasm_bindgen_test::wasm_bindgen_test]
+            pub fn [<$name _portable>]() {
+                [<$name _portable_version>]()
+            }
+        }
+    }
+}
+
+pub mod any_token;
+pub mod array;
+pub mod bitcast;
+pub mod bitwise;
+pub mod classify;
+pub mod composite;
+pub mod convert;
+pub mod dispatch;
+pub mod emulated;
+pub mod extension;
+pub mod horizontal;
+pub mod interpolate;
+pub mod methods;
+pub mod numbered;
+#[cfg(feature = "nightly")]
+pub mod portable;
+pub mod ramp;
+pub mod scan;
+pub mod stream;
+pub mod wrapping;
+
 #[cfg(feature = "complex")]
 use num_complex::{Complex, ComplexDistribution};
 
@@ -148,27 +251,35 @@ macro_rules! ops_test {
     } => {
         pub mod width_native {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed_native }
+            ops_test! { @wrapper $token, $type, [zeroed_native] }
         }
         pub mod width_1 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed1 }
+            ops_test! { @wrapper $token, $type, [zeroed_lanes::<1>] }
         }
         pub mod width_2 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed2 }
+            ops_test! { @wrapper $token, $type, [zeroed_lanes::<2>] }
         }
         pub mod width_4 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed4 }
+            ops_test! { @wrapper $token, $type, [zeroed_lanes::<4>] }
         }
         pub mod width_8 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed8 }
+            ops_test! { @wrapper $token, $type, [zeroed_lanes::<8>] }
+        }
+        pub mod width_16 {
+            use super::*;
+            ops_test! { @wrapper $token, $type, [zeroed_lanes::<16>] }
+        }
+        pub mod width_32 {
+            use super::*;
+            ops_test! { @wrapper $token, $type, [zeroed_lanes::<32>] }
         }
     };
     {
-        @wrapper $token:ident, $type:ty, $init:ident
+        @wrapper $token:ident, $type:ty, $init:tt
     } => {
         ops_test! { @impl $type, $init, add,               binary_op_impl,        $token, core::ops::Add::add }
         ops_test! { @impl $type, $init, sub,               binary_op_impl,        $token, core::ops::Sub::sub }
@@ -190,35 +301,36 @@ macro_rules! ops_test {
     };
     { @distribution f32 } => { Standard };
     { @distribution f64 } => { Standard };
+    { @distribution i32 } => { Uniform::new(1, 1000) };
     { @distribution Complex<f32> } => { ComplexDistribution::new(Standard, Standard) };
     { @distribution Complex<f64> } => { ComplexDistribution::new(Standard, Standard) };
     {
-        @init unary_op_impl, $type:ty, $token:ident, $init:ident
+        @init unary_op_impl, $type:ty, $token:ident, [$($init:tt)*]
     } => {
-        <$type>::$init($token)
+        <$type>::$($init)*($token)
     };
     {
-        @init binary_op_impl, $type:ty, $token:ident, $init:ident
+        @init binary_op_impl, $type:ty, $token:ident, [$($init:tt)*]
     } => {
-        (<$type>::$init($token), <$type>::$init($token))
+        (<$type>::$($init)*($token), <$type>::$($init)*($token))
     };
     {
-        @init binary_scalar_op_impl, $type:ty, $token:ident, $init:ident
+        @init binary_scalar_op_impl, $type:ty, $token:ident, [$($init:tt)*]
     } => {
-        <$type>::$init($token)
+        <$type>::$($init)*($token)
     };
     {
-        @init assign_op_impl, $type:ty, $token:ident, $init:ident
+        @init assign_op_impl, $type:ty, $token:ident, [$($init:tt)*]
     } => {
-        (<$type>::$init($token), <$type>::$init($token))
+        (<$type>::$($init)*($token), <$type>::$($init)*($token))
     };
     {
-        @init assign_scalar_op_impl, $type:ty, $token:ident, $init:ident
+        @init assign_scalar_op_impl, $type:ty, $token:ident, [$($init:tt)*]
     } => {
-        <$type>::$init($token)
+        <$type>::$($init)*($token)
     };
     {
-        @impl $type:ty, $init:ident, $name:ident, $test:ident, $token:ident, $func:path
+        @impl $type:ty, $init:tt, $name:ident, $test:ident, $token:ident, $func:path
     } => {
         paste::paste! {
             #[dispatch($token)]
@@ -241,6 +353,15 @@ macro_rules! ops_test {
                 }
             }
 
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _sse2>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::Sse2::new().is_some() {
+                    unsafe { [<$name _dispatch_sse2_version>]() }
+                }
+            }
+
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             #[test]
             pub fn [<$name _avx>]() {
@@ -250,6 +371,24 @@ macro_rules! ops_test {
                 }
             }
 
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _x86v2>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::X86V2::new().is_some() {
+                    unsafe { [<$name _dispatch_popcnt_sse3_sse41_sse42_ssse3_version>]() }
+                }
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _x86v3>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::X86V3::new().is_some() {
+                    unsafe { [<$name _dispatch_avx2_bmi1_bmi2_f16c_fma_lzcnt_popcnt_version>]() }
+                }
+            }
+
             #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
             #[test]
             pub fn [<$name _neon>]() {
@@ -266,6 +405,13 @@ macro_rules! ops_test {
                 assert!(generic_simd::arch::wasm::Simd128::new().is_some());
                 unsafe { [<$name _dispatch_simd128_version>]() }
             }
+
+            #[cfg(feature = "nightly")]
+            #[test]
+            #[wasm_bindgen_test::wasm_bindgen_test]
+            pub fn [<$name _portable>]() {
+                [<$name _dispatch_portable_version>]()
+            }
         }
     };
 }
@@ -280,6 +426,11 @@ pub mod r#f64 {
     ops_test! { token, f64 }
 }
 
+pub mod r#i32 {
+    use super::*;
+    ops_test! { token, i32 }
+}
+
 #[cfg(feature = "complex")]
 pub mod complex_f32 {
     use super::*;
@@ -0,0 +1,177 @@
+#[cfg(test)]
+use generic_simd::arch::AnyToken;
+use generic_simd::{
+    arch::Token, call_macro_with_tokens, dispatch, scalar::ScalarExt, slice::SliceExt,
+    vector::NativeVector,
+};
+
+macro_rules! supported_scalar {
+    { $($token:ty,)+ } => {
+        // Scalars that can be summed with every token.
+        pub trait SupportedScalar:
+            Copy
+            + core::ops::Add<Output = Self>
+            + core::iter::Sum<Self>
+            $(+ ScalarExt<$token> + core::iter::Sum<NativeVector<Self, $token>>)*
+        {
+        }
+
+        impl<S> SupportedScalar for S where
+            S: Copy
+                + core::ops::Add<Output = Self>
+                + core::iter::Sum<Self>
+                $(+ ScalarExt<$token> + core::iter::Sum<NativeVector<Self, $token>>)*
+        {
+        }
+    }
+}
+
+call_macro_with_tokens! { supported_scalar }
+
+// Sums a slice with native vectors.
+#[inline]
+fn sum_native<T, S>(token: T, input: &[S]) -> S
+where
+    T: Token,
+    S: ScalarExt<T>
+        + core::ops::Add<Output = S>
+        + core::iter::Sum<S>
+        + core::iter::Sum<NativeVector<S, T>>,
+{
+    let (start, vectors, end) = input.align_native(token);
+    start.iter().chain(end).copied().sum::<S>() + vectors.iter().copied().sum::<S>()
+}
+
+pub struct Scale(pub f32);
+
+impl Scale {
+    #[dispatch(token)]
+    pub fn apply(&self, input: &mut [f32]) {
+        let (start, vectors, end) = input.align_native_mut(token);
+        for x in start.iter_mut().chain(end) {
+            *x *= self.0;
+        }
+        for v in vectors {
+            *v *= self.0;
+        }
+    }
+
+    #[dispatch(token, report)]
+    pub fn new_scaled(scale: f32, input: &[f32]) -> Self {
+        Self(scale * sum_native(token, input))
+    }
+}
+
+#[test]
+fn method() {
+    let mut input = (0..17).map(|x| x as f32).collect::<Vec<_>>();
+    Scale(2.).apply(&mut input);
+    for (i, x) in input.iter().enumerate() {
+        assert_eq!(*x, (2 * i) as f32);
+    }
+    assert_eq!(Scale::new_scaled(2., &[1.; 9]).0, 18.);
+    assert_eq!(Scale::new_scaled_selected_version(), AnyToken::new().name());
+}
+
+#[dispatch(token)]
+pub fn sum_generic<S: SupportedScalar>(input: &[S]) -> S {
+    sum_native(token, input)
+}
+
+#[test]
+fn generic() {
+    assert_eq!(sum_generic(&[1f64; 10]), 10.);
+    assert_eq!(sum_generic(&[1i32; 10]), 10);
+}
+
+pub struct Accumulator {
+    total: f64,
+}
+
+#[dispatch(token, report)]
+impl Accumulator {
+    pub fn new() -> Self {
+        Self { total: 0. }
+    }
+
+    pub fn add(&mut self, input: &[f64]) {
+        self.total += sum_native(token, input);
+    }
+
+    pub fn total(&self) -> f64 {
+        self.total
+    }
+}
+
+#[test]
+fn inherent_impl() {
+    let mut acc = Accumulator::new();
+    acc.add(&[1.; 7]);
+    acc.add(&[2.; 5]);
+    assert_eq!(acc.total(), 17.);
+    assert_eq!(Accumulator::add_selected_version(), AnyToken::new().name());
+}
+
+pub trait Kernel<S> {
+    fn run(&self, input: &[S]) -> S;
+
+    fn run_pair(&self, pair: (&[S], &[S])) -> (S, S);
+}
+
+pub struct Sum;
+
+#[dispatch(token, report)]
+impl<S: SupportedScalar> Kernel<S> for Sum {
+    fn run(&self, input: &[S]) -> S {
+        sum_native(token, input)
+    }
+
+    fn run_pair(&self, (a, b): (&[S], &[S])) -> (S, S) {
+        (self.run(a), self.run(b))
+    }
+}
+
+#[test]
+fn trait_impl() {
+    assert_eq!(Kernel::<f32>::run(&Sum, &[1.; 11]), 11.);
+    assert_eq!(Sum.run_pair((&[1u32; 3][..], &[2u32; 3][..])), (3, 6));
+    assert_eq!(Sum::kernel_run_selected_version(), AnyToken::new().name());
+}
+
+pub trait Double {
+    fn total(&self, input: &[f32]) -> f32;
+}
+
+pub trait Halve {
+    fn total(&self, input: &[f32]) -> f32;
+}
+
+pub struct Scaled;
+
+#[dispatch(token, report)]
+impl Double for Scaled {
+    fn total(&self, input: &[f32]) -> f32 {
+        2. * sum_native(token, input)
+    }
+}
+
+#[dispatch(token, report)]
+impl Halve for Scaled {
+    fn total(&self, input: &[f32]) -> f32 {
+        sum_native(token, input) / 2.
+    }
+}
+
+#[test]
+fn shared_method_name() {
+    assert_eq!(Double::total(&Scaled, &[1.; 6]), 12.);
+    assert_eq!(Halve::total(&Scaled, &[1.; 6]), 3.);
+    assert_eq!(
+        Scaled::double_total_selected_version(),
+        AnyToken::new().name()
+    );
+    assert_eq!(
+        Scaled::halve_total_selected_version(),
+        AnyToken::new().name()
+    );
+}
@@ -0,0 +1,49 @@
+use generic_simd::{
+    dispatch, pointer::PointerExt, scalar::ScalarExt, slice::SliceExt, vector::Vector,
+};
+
+// Checks the numbered methods against the const-generic methods they forward to.
+macro_rules! numbered_test {
+    {
+        $name:ident, $lanes:literal, $zeroed:ident, $splat:ident, $read:ident, $align:ident,
+        $overlapping:ident, $vector_read:ident
+    } => {
+        #[dispatch(token)]
+        pub fn $name() {
+            let mut data = [0f32; 3 * $lanes + 1];
+            for (i, x) in data.iter_mut().enumerate() {
+                *x = i as f32;
+            }
+
+            assert_eq!(f32::$zeroed(token).as_slice(), [0.; $lanes]);
+            assert_eq!(2f32.$splat(token).as_slice(), [2.; $lanes]);
+
+            let read = data[1..].$read(token);
+            assert_eq!(read.as_slice(), &data[1..$lanes + 1]);
+            let read_ptr = unsafe { data[1..].as_ptr().$vector_read(token) };
+            assert_eq!(read_ptr.as_slice(), read.as_slice());
+
+            let (head, body, tail) = data.$align(token);
+            let (head_lanes, body_lanes, tail_lanes) = data.align_lanes::<$lanes>(token);
+            assert_eq!(head, head_lanes);
+            assert_eq!(tail, tail_lanes);
+            assert_eq!(body.len(), body_lanes.len());
+            for (i, v) in body.iter().enumerate() {
+                assert_eq!(v.as_slice(), &data[head.len() + i * $lanes..][..$lanes]);
+            }
+
+            let overlapping = data.$overlapping(token);
+            assert_eq!(overlapping.len(), data.len() - $lanes + 1);
+            assert_eq!(overlapping.get(1).unwrap().as_slice(), &data[1..$lanes + 1]);
+        }
+
+        dispatch_test! { $name }
+    }
+}
+
+numbered_test! { numbered1, 1, zeroed1, splat1, read1, align1, overlapping1, vector_read1 }
+numbered_test! { numbered2, 2, zeroed2, splat2, read2, align2, overlapping2, vector_read2 }
+numbered_test! { numbered4, 4, zeroed4, splat4, read4, align4, overlapping4, vector_read4 }
+numbered_test! { numbered8, 8, zeroed8, splat8, read8, align8, overlapping8, vector_read8 }
+numbered_test! { numbered16, 16, zeroed16, splat16, read16, align16, overlapping16, vector_read16 }
+numbered_test! { numbered32, 32, zeroed32, splat32, read32, align32, overlapping32, vector_read32 }
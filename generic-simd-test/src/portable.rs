@@ -0,0 +1,84 @@
+use generic_simd::{arch::AnyToken, dispatch};
+
+#[cfg(test)]
+use generic_simd::{
+    arch::{portable::Portable, Token},
+    scalar::ScalarExt,
+    slice::SliceExt,
+    vector::{width, Bitwise, ConvertToInt, Horizontal, NativeVector, NativeWidth, Vector},
+};
+
+#[test]
+fn portable_token() {
+    let token = Portable::new().unwrap();
+    assert_eq!(AnyToken::from(token).name(), "portable");
+    assert_eq!(<NativeWidth<f32, Portable> as width::Width>::VALUE, 4);
+    assert_eq!(<NativeWidth<f64, Portable> as width::Width>::VALUE, 2);
+}
+
+#[test]
+fn portable_ops() {
+    let token = Portable::get();
+    let a = 2f32.splat_native(token);
+    let b = NativeVector::<f32, Portable>::from_array(token, [1., 2., 3., 4.]);
+    assert_eq!(((a * b - a) / a).to_array(), [0., 1., 2., 3.]);
+    assert_eq!((-b).horizontal_sum(), -10.);
+    assert_eq!((b ^ a ^ a).to_array(), b.to_array());
+    assert_eq!(b.and_not(b).to_array(), [0.; 4]);
+
+    let converted: NativeVector<i32, Portable> = b.convert_truncate(token);
+    assert_eq!(converted.to_array(), [1, 2, 3, 4]);
+    assert_eq!((-converted * 2).to_array(), [-2, -4, -6, -8]);
+
+    let c = u64::MAX.splat_native(token) + 2;
+    assert_eq!(c.to_array(), [1, 1]);
+}
+
+#[test]
+fn portable_sum() {
+    let token = Portable::get();
+    let input = (0..100).map(|x| x as f64).collect::<Vec<_>>();
+    let (start, vectors, end) = input.align_native(token);
+    let sum = start.iter().chain(end).sum::<f64>() + vectors.iter().copied().sum::<f64>();
+    assert_eq!(sum, 4950.);
+}
+
+#[dispatch(token, report, tokens = [Portable, Generic])]
+pub fn portable_only() -> String {
+    AnyToken::from(token).name().to_string()
+}
+
+#[test]
+fn portable_dispatch() {
+    assert_eq!(portable_only(), "portable");
+    assert_eq!(portable_only_selected_version(), "portable");
+}
+
+#[dispatch(token, tokens = [Portable, Generic])]
+pub fn portable_pattern((a, b): (f32, f32), scale: impl Into<f32>) -> (String, f32) {
+    (
+        AnyToken::from(token).name().to_string(),
+        (a + b) * scale.into(),
+    )
+}
+
+pub struct Prefixed(pub String);
+
+#[dispatch(token, tokens = [Portable, Generic])]
+impl Prefixed {
+    pub fn name(&self, [separator]: [&str; 1]) -> String {
+        format!("{}{}{}", self.0, separator, AnyToken::from(token).name())
+    }
+}
+
+#[test]
+fn portable_forwarding() {
+    assert_eq!(
+        portable_pattern((1., 2.), 2f32),
+        ("portable".to_string(), 6.)
+    );
+    assert_eq!(
+        Prefixed("version".to_string()).name([": "]),
+        "version: portable"
+    );
+}
@@ -0,0 +1,123 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    slice::SliceExt,
+    vector::{width, Ops, Vector, VectorOf},
+};
+
+#[inline]
+fn ramp_impl<T, W>(token: T)
+where
+    T: Token,
+    W: width::Width,
+    f32: Scalar<T, W>,
+    i32: Scalar<T, W>,
+    VectorOf<f32, W, T>: Ops,
+{
+    let iota = VectorOf::<i32, W, T>::iota(token);
+    let ramp = VectorOf::<f32, W, T>::ramp(token, 1.5, -0.25);
+    for i in 0..W::VALUE {
+        assert_eq!(iota.as_slice()[i], i as i32);
+        assert_eq!(ramp.as_slice()[i], i as f32 * -0.25 + 1.5);
+    }
+}
+
+#[dispatch(token)]
+pub fn ramp() {
+    ramp_impl::<_, width::W1>(token);
+    ramp_impl::<_, width::W2>(token);
+    ramp_impl::<_, width::W4>(token);
+    ramp_impl::<_, width::W8>(token);
+    ramp_impl::<_, width::W16>(token);
+    ramp_impl::<_, width::W32>(token);
+}
+
+dispatch_test! { ramp }
+
+#[dispatch(token)]
+pub fn arange() {
+    let mut buffer = [0f64; 37];
+    for offset in 0..4 {
+        for len in 0..buffer.len() - offset {
+            let slice = &mut buffer[offset..offset + len];
+            slice.arange_native(token, -2., 0.5);
+            for (i, x) in slice.iter().enumerate() {
+                assert_eq!(*x, i as f64 * 0.5 - 2.);
+            }
+        }
+    }
+}
+
+dispatch_test! { arange }
+
+#[dispatch(token)]
+pub fn arange_infinite() {
+    let mut buffer = [0f64; 37];
+    for offset in 0..4 {
+        for len in 1..buffer.len() - offset {
+            let slice = &mut buffer[offset..offset + len];
+            slice.arange_native(token, f64::INFINITY, 1.);
+            assert!(slice.iter().all(|x| *x == f64::INFINITY));
+
+            slice.arange_native(token, 1., f64::INFINITY);
+            assert_eq!(slice[0], 1.);
+            assert!(slice[1..].iter().all(|x| *x == f64::INFINITY));
+
+            slice.arange_native(token, f64::NEG_INFINITY, f64::NEG_INFINITY);
+            assert!(slice.iter().all(|x| *x == f64::NEG_INFINITY));
+        }
+    }
+}
+
+dispatch_test! { arange_infinite }
+
+#[dispatch(token)]
+pub fn linspace() {
+    let mut buffer = [0f32; 37];
+    for offset in 0..4 {
+        for len in 0..buffer.len() - offset {
+            let slice = &mut buffer[offset..offset + len];
+            slice.linspace_native(token, 1., 3.);
+            for (i, x) in slice.iter().enumerate() {
+                if i == 0 {
+                    assert_eq!(*x, 1.);
+                } else if i == len - 1 {
+                    assert_eq!(*x, 3.);
+                } else {
+                    assert_eq!(*x, i as f32 * (2. / (len - 1) as f32) + 1.);
+                }
+            }
+        }
+    }
+}
+
+dispatch_test! { linspace }
+
+#[dispatch(token)]
+pub fn linspace_edge_cases() {
+    let mut buffer = [0f32; 37];
+    for offset in 0..4 {
+        let slice = &mut buffer[offset..offset];
+        slice.linspace_native(token, f32::INFINITY, 1.);
+
+        for start in [1., f32::INFINITY, f32::NEG_INFINITY].iter() {
+            let slice = &mut buffer[offset..offset + 1];
+            slice.linspace_native(token, *start, 3.);
+            assert_eq!(slice[0], *start);
+        }
+
+        for len in 2..buffer.len() - offset {
+            let slice = &mut buffer[offset..offset + len];
+            slice.linspace_native(token, f32::NEG_INFINITY, 3.);
+            assert_eq!(slice[0], f32::NEG_INFINITY);
+            assert_eq!(slice[len - 1], 3.);
+
+            slice.linspace_native(token, 1., f32::INFINITY);
+            assert_eq!(slice[0], 1.);
+            assert_eq!(slice[len - 1], f32::INFINITY);
+        }
+    }
+}
+
+dispatch_test! { linspace_edge_cases }
@@ -0,0 +1,96 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    slice::SliceExt,
+    vector::{width, PrefixSum, Vector, VectorOf},
+};
+
+macro_rules! scan_test {
+    { $name:ident, $scalar:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>,
+            VectorOf<$scalar, W, T>: PrefixSum,
+        {
+            let v = VectorOf::<$scalar, W, T>::from_fn(token, |i| (i * 3 + 1) as $scalar);
+            let inclusive = v.prefix_sum();
+            let exclusive = v.exclusive_prefix_sum();
+            let mut sum = 0 as $scalar;
+            for i in 0..W::VALUE {
+                assert_eq!(exclusive.as_slice()[i], sum);
+                sum += v.as_slice()[i];
+                assert_eq!(inclusive.as_slice()[i], sum);
+            }
+        }
+    }
+}
+
+scan_test! { scan_f32, f32 }
+scan_test! { scan_f64, f64 }
+scan_test! { scan_i32, i32 }
+scan_test! { scan_u64, u64 }
+
+#[dispatch(token)]
+pub fn scan() {
+    scan_f32::<_, width::W1>(token);
+    scan_f32::<_, width::W2>(token);
+    scan_f32::<_, width::W4>(token);
+    scan_f32::<_, width::W8>(token);
+    scan_f32::<_, width::W16>(token);
+    scan_f32::<_, width::W32>(token);
+    scan_f64::<_, width::W1>(token);
+    scan_f64::<_, width::W2>(token);
+    scan_f64::<_, width::W4>(token);
+    scan_f64::<_, width::W8>(token);
+    scan_f64::<_, width::W16>(token);
+    scan_f64::<_, width::W32>(token);
+    scan_i32::<_, width::W1>(token);
+    scan_i32::<_, width::W2>(token);
+    scan_i32::<_, width::W4>(token);
+    scan_i32::<_, width::W8>(token);
+    scan_i32::<_, width::W16>(token);
+    scan_i32::<_, width::W32>(token);
+    scan_u64::<_, width::W1>(token);
+    scan_u64::<_, width::W2>(token);
+    scan_u64::<_, width::W4>(token);
+    scan_u64::<_, width::W8>(token);
+    scan_u64::<_, width::W16>(token);
+    scan_u64::<_, width::W32>(token);
+}
+
+dispatch_test! { scan }
+
+#[dispatch(token)]
+pub fn scan_slice() {
+    let mut buffer = [0f32; 37];
+    for offset in 0..4 {
+        for len in 0..buffer.len() - offset {
+            let slice = &mut buffer[offset..offset + len];
+            for (i, x) in slice.iter_mut().enumerate() {
+                *x = (i % 5) as f32;
+            }
+            slice.prefix_sum_native(token);
+            let mut sum = 0.;
+            for (i, x) in slice.iter().enumerate() {
+                sum += (i % 5) as f32;
+                assert_eq!(*x, sum);
+            }
+
+            for (i, x) in slice.iter_mut().enumerate() {
+                *x = (i % 5) as f32;
+            }
+            slice.exclusive_prefix_sum_native(token);
+            let mut sum = 0.;
+            for (i, x) in slice.iter().enumerate() {
+                assert_eq!(*x, sum);
+                sum += (i % 5) as f32;
+            }
+        }
+    }
+}
+
+dispatch_test! { scan_slice }
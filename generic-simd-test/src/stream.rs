@@ -0,0 +1,89 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    pointer::Locality,
+    scalar::Scalar,
+    vector::{width, Vector, VectorOf},
+};
+
+const LOCALITIES: [Locality; 4] = [
+    Locality::None,
+    Locality::Low,
+    Locality::Moderate,
+    Locality::High,
+];
+
+macro_rules! stream_test {
+    { $name:ident, $scalar:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>,
+        {
+            // A buffer of vectors is aligned for the vector type.
+            let mut buffer = vec![VectorOf::<$scalar, W, T>::zeroed(token); 4];
+            let ptr = buffer.as_mut_ptr() as *mut $scalar;
+
+            for (i, locality) in LOCALITIES.iter().enumerate() {
+                let to = unsafe { ptr.add(i * W::VALUE) };
+                VectorOf::<$scalar, W, T>::prefetch_write_ptr(token, to, *locality);
+                let v = VectorOf::<$scalar, W, T>::from_fn(token, |j| (i * W::VALUE + j + 1) as $scalar);
+                unsafe { v.write_stream_aligned_ptr(to) };
+            }
+            VectorOf::<$scalar, W, T>::stream_fence(token);
+
+            for (i, locality) in LOCALITIES.iter().enumerate() {
+                let from = unsafe { ptr.add(i * W::VALUE) };
+                VectorOf::<$scalar, W, T>::prefetch_read_ptr(token, from, *locality);
+                let v = unsafe { VectorOf::<$scalar, W, T>::read_aligned_ptr(token, from) };
+                for j in 0..W::VALUE {
+                    assert_eq!(v.as_slice()[j], (i * W::VALUE + j + 1) as $scalar);
+                }
+            }
+        }
+    }
+}
+
+stream_test! { stream_f32, f32 }
+stream_test! { stream_f64, f64 }
+stream_test! { stream_i32, i32 }
+stream_test! { stream_u32, u32 }
+stream_test! { stream_u64, u64 }
+
+#[dispatch(token)]
+pub fn stream() {
+    stream_f32::<_, width::W1>(token);
+    stream_f32::<_, width::W2>(token);
+    stream_f32::<_, width::W4>(token);
+    stream_f32::<_, width::W8>(token);
+    stream_f32::<_, width::W16>(token);
+    stream_f32::<_, width::W32>(token);
+    stream_f64::<_, width::W1>(token);
+    stream_f64::<_, width::W2>(token);
+    stream_f64::<_, width::W4>(token);
+    stream_f64::<_, width::W8>(token);
+    stream_f64::<_, width::W16>(token);
+    stream_f64::<_, width::W32>(token);
+    stream_i32::<_, width::W1>(token);
+    stream_i32::<_, width::W2>(token);
+    stream_i32::<_, width::W4>(token);
+    stream_i32::<_, width::W8>(token);
+    stream_i32::<_, width::W16>(token);
+    stream_i32::<_, width::W32>(token);
+    stream_u32::<_, width::W1>(token);
+    stream_u32::<_, width::W2>(token);
+    stream_u32::<_, width::W4>(token);
+    stream_u32::<_, width::W8>(token);
+    stream_u32::<_, width::W16>(token);
+    stream_u32::<_, width::W32>(token);
+    stream_u64::<_, width::W1>(token);
+    stream_u64::<_, width::W2>(token);
+    stream_u64::<_, width::W4>(token);
+    stream_u64::<_, width::W8>(token);
+    stream_u64::<_, width::W16>(token);
+    stream_u64::<_, width::W32>(token);
+}
+
+dispatch_test! { stream }
@@ -0,0 +1,96 @@
+use generic_simd::{
+    arch::Token,
+    dispatch,
+    scalar::Scalar,
+    vector::{width, Ops, Vector, VectorOf},
+};
+
+macro_rules! wrapping_test {
+    { $name:ident, $scalar:ty } => {
+        #[inline]
+        fn $name<T, W>(token: T)
+        where
+            T: Token,
+            W: width::Width,
+            $scalar: Scalar<T, W>
+                + core::iter::Sum<VectorOf<$scalar, W, T>>
+                + core::iter::Product<VectorOf<$scalar, W, T>>,
+            VectorOf<$scalar, W, T>: Ops,
+        {
+            let a = VectorOf::<$scalar, W, T>::from_fn(token, |i| <$scalar>::MAX - i as $scalar);
+            let b = VectorOf::<$scalar, W, T>::from_fn(token, |i| <$scalar>::MAX / 3 + i as $scalar);
+            let lanes = a.iter().chain(b.iter()).copied().collect::<Vec<_>>();
+
+            assert_eq!(
+                vec![a, b].into_iter().sum::<$scalar>(),
+                lanes.iter().fold(0 as $scalar, |x, y| x.wrapping_add(*y))
+            );
+            assert_eq!(
+                vec![a, b].into_iter().product::<$scalar>(),
+                lanes.iter().fold(1 as $scalar, |x, y| x.wrapping_mul(*y))
+            );
+
+            let sums = a + b;
+            let products = a * b;
+            for i in 0..W::VALUE {
+                assert_eq!(sums.as_slice()[i], a.as_slice()[i].wrapping_add(b.as_slice()[i]));
+                assert_eq!(products.as_slice()[i], a.as_slice()[i].wrapping_mul(b.as_slice()[i]));
+            }
+        }
+    }
+}
+
+wrapping_test! { wrapping_i32, i32 }
+wrapping_test! { wrapping_u32, u32 }
+wrapping_test! { wrapping_u64, u64 }
+
+#[inline]
+fn wrapping_div<T, W>(token: T)
+where
+    T: Token,
+    W: width::Width,
+    i32: Scalar<T, W>,
+    VectorOf<i32, W, T>: Ops,
+{
+    let min = VectorOf::<i32, W, T>::splat(token, i32::MIN);
+    let negative_one = VectorOf::<i32, W, T>::splat(token, -1);
+
+    let mut assigned = min;
+    assigned /= negative_one;
+    let mut assigned_scalar = min;
+    assigned_scalar /= -1;
+
+    for quotient in [min / negative_one, min / -1, assigned, assigned_scalar].iter() {
+        assert!(quotient.iter().all(|x| *x == i32::MIN));
+    }
+}
+
+#[dispatch(token)]
+pub fn wrapping() {
+    wrapping_i32::<_, width::W1>(token);
+    wrapping_i32::<_, width::W2>(token);
+    wrapping_i32::<_, width::W4>(token);
+    wrapping_i32::<_, width::W8>(token);
+    wrapping_i32::<_, width::W16>(token);
+    wrapping_i32::<_, width::W32>(token);
+    wrapping_u32::<_, width::W1>(token);
+    wrapping_u32::<_, width::W2>(token);
+    wrapping_u32::<_, width::W4>(token);
+    wrapping_u32::<_, width::W8>(token);
+    wrapping_u32::<_, width::W16>(token);
+    wrapping_u32::<_, width::W32>(token);
+    wrapping_u64::<_, width::W1>(token);
+    wrapping_u64::<_, width::W2>(token);
+    wrapping_u64::<_, width::W4>(token);
+    wrapping_u64::<_, width::W8>(token);
+    wrapping_u64::<_, width::W16>(token);
+    wrapping_u64::<_, width::W32>(token);
+    wrapping_div::<_, width::W1>(token);
+    wrapping_div::<_, width::W2>(token);
+    wrapping_div::<_, width::W4>(token);
+    wrapping_div::<_, width::W8>(token);
+    wrapping_div::<_, width::W16>(token);
+    wrapping_div::<_, width::W32>(token);
+}
+
+dispatch_test! { wrapping }
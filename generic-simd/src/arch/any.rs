@@ -0,0 +1,494 @@
+use crate::arch::{generic::Generic, Token};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::arch::x86::{Avx, Sse, Sse2, X86V2, X86V3};
+
+#[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+use crate::arch::arm::Neon;
+
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "nightly",
+))]
+use crate::arch::wasm::Simd128;
+
+#[cfg(feature = "nightly")]
+use crate::arch::portable::Portable;
+
+/// A function that can be called with a particular token.
+///
+/// Closures can't be generic, so a function dispatched with [`AnyToken::dispatch`] is a type
+/// implementing `Dispatch` for every supported token, usually with a single generic impl.
+/// Each token gets its own monomorphized copy of [`call`](Dispatch::call), compiled with that
+/// token's CPU features enabled.
+///
+/// ```
+/// use generic_simd::{
+///     arch::{AnyToken, Dispatch, Token},
+///     scalar::ScalarExt,
+///     slice::SliceExt,
+///     vector::NativeVector,
+/// };
+///
+/// struct Sum<'a>(&'a [f32]);
+///
+/// impl<T> Dispatch<T> for Sum<'_>
+/// where
+///     T: Token,
+///     f32: ScalarExt<T> + core::iter::Sum<NativeVector<f32, T>>,
+/// {
+///     type Output = f32;
+///
+///     #[inline]
+///     fn call(self, token: T) -> f32 {
+///         let (start, vectors, end) = self.0.align_native(token);
+///         start.iter().chain(end).sum::<f32>() + vectors.iter().copied().sum::<f32>()
+///     }
+/// }
+///
+/// let input = [1., 2., 3., 4., 5., 6., 7., 8., 9., 10.];
+/// assert_eq!(AnyToken::new().dispatch(Sum(&input)), 55.);
+/// ```
+pub trait Dispatch<T: Token> {
+    /// The value returned by the function.
+    type Output;
+
+    /// Calls the function.
+    ///
+    /// This should be marked `#[inline]` so it's compiled with the token's CPU features.
+    fn call(self, token: T) -> Self::Output;
+}
+
+macro_rules! dispatch_all {
+    { $($token:ty,)+ } => {
+        /// A function that can be called with any supported token.
+        ///
+        /// This trait is implemented for every type that implements [`Dispatch`] for each of the
+        /// supported tokens with the same output.
+        pub trait DispatchAll<Output>: $(Dispatch<$token, Output = Output> +)* {}
+
+        impl<F, Output> DispatchAll<Output> for F where F: $(Dispatch<$token, Output = Output> +)* {}
+    }
+}
+
+crate::call_macro_with_tokens! { dispatch_all }
+
+macro_rules! supported_tokens {
+    { $($token:ty,)+ } => {
+        /// Returns the tokens supported by the CPU, in priority order.
+        ///
+        /// The tokens are limited by [`set_max_token`].
+        ///
+        /// ```
+        /// let names = generic_simd::arch::supported_tokens()
+        ///     .map(|token| token.name())
+        ///     .collect::<Vec<_>>();
+        /// assert_eq!(names.last(), Some(&"generic"));
+        /// ```
+        pub fn supported_tokens() -> impl Iterator<Item = AnyToken> {
+            core::iter::empty()$(.chain(<$token>::new().map(AnyToken::from)))*
+        }
+    }
+}
+
+crate::call_macro_with_tokens! { supported_tokens }
+
+/// Any supported token, detected at runtime.
+///
+/// The variants depend on the target architecture.
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub enum AnyToken {
+    /// x86-64-v3 microarchitecture level.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    X86V3(X86V3),
+
+    /// AVX instruction set.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx(Avx),
+
+    /// x86-64-v2 microarchitecture level.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    X86V2(X86V2),
+
+    /// SSE4.1 instruction set.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sse(Sse),
+
+    /// SSE2 instruction set.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sse2(Sse2),
+
+    /// NEON instruction set.
+    #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+    Neon(Neon),
+
+    /// SIMD128 instruction set.
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        feature = "nightly",
+    ))]
+    Simd128(Simd128),
+
+    /// Portable SIMD, implemented with `core::simd`.
+    #[cfg(feature = "nightly")]
+    Portable(Portable),
+
+    /// Generic instruction set.
+    Generic(Generic),
+}
+
+// The rank of the detected variant, plus one so that zero means not yet detected.
+static DETECTED: AtomicU8 = AtomicU8::new(0);
+
+// The rank of the maximum token, plus one so that zero means not yet read from the environment.
+static MAX_RANK: AtomicU8 = AtomicU8::new(0);
+
+// Stored in `MAX_RANK` when no maximum is set.
+const UNLIMITED: u8 = u8::MAX;
+
+/// The environment variable that limits the detected tokens, unless [`set_max_token`] is called.
+///
+/// The variable is read on the first detection. Its value is the name of a token, such as
+/// `generic`, `sse`, or `avx` (case-insensitive). Unrecognized names are ignored.
+///
+/// ```
+/// use generic_simd::arch::{AnyToken, MAX_TOKEN_VAR};
+///
+/// std::env::set_var(MAX_TOKEN_VAR, "Generic");
+/// assert!(matches!(AnyToken::new(), AnyToken::Generic(_)));
+/// ```
+pub const MAX_TOKEN_VAR: &str = "GENERIC_SIMD_MAX_TOKEN";
+
+/// Limits the tokens that can be detected, process-wide.
+///
+/// After calling this function, [`Token::new`] returns `None` for tokens better than `token`,
+/// and [`dispatch`](crate::dispatch) and [`AnyToken::new`] select `token` at best.
+/// Without a call to this function, the limit is read from the [`MAX_TOKEN_VAR`] environment
+/// variable (with the `std` feature).
+///
+/// This is intended for testing and benchmarking fallback code paths.
+/// Functions tagged with `dispatch` remember the version selected by their first call, so this
+/// should be called before any dispatched function.
+/// Their `_selected_version` report functions detect the version again on each call, so they may
+/// not match the remembered version if this is called later.
+///
+/// ```
+/// use generic_simd::{
+///     arch::{generic::Generic, set_max_token, AnyToken, Token},
+///     dispatch,
+/// };
+///
+/// #[dispatch(token)]
+/// fn token_name() -> String {
+///     format!("{:?}", token)
+/// }
+///
+/// set_max_token(Generic);
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// assert!(generic_simd::arch::x86::Sse::new().is_none());
+/// assert!(matches!(AnyToken::new(), AnyToken::Generic(_)));
+/// assert_eq!(token_name(), "Generic");
+/// ```
+pub fn set_max_token(token: impl Into<AnyToken>) {
+    MAX_RANK.store(token.into().rank() + 1, Ordering::Relaxed);
+    DETECTED.store(0, Ordering::Relaxed);
+}
+
+fn max_rank() -> u8 {
+    match MAX_RANK.load(Ordering::Relaxed) {
+        0 => {
+            let rank = max_rank_from_env();
+            // Don't overwrite a concurrent call to `set_max_token`.
+            let _ = MAX_RANK.compare_exchange(0, rank, Ordering::Relaxed, Ordering::Relaxed);
+            MAX_RANK.load(Ordering::Relaxed)
+        }
+        rank => rank,
+    }
+}
+
+#[cfg(feature = "std")]
+fn max_rank_from_env() -> u8 {
+    std::env::var(MAX_TOKEN_VAR)
+        .ok()
+        .and_then(|name| AnyToken::rank_of(&name.to_ascii_lowercase()))
+        .map_or(UNLIMITED, |rank| rank + 1)
+}
+
+#[cfg(not(feature = "std"))]
+fn max_rank_from_env() -> u8 {
+    UNLIMITED
+}
+
+/// Returns whether the CPU features are permitted by [`set_max_token`].
+#[doc(hidden)]
+pub fn features_allowed(features: &[&str]) -> bool {
+    let max = max_rank();
+    max == UNLIMITED
+        || features
+            .iter()
+            .all(|feature| AnyToken::feature_rank(feature) < max)
+}
+
+// The portable token doesn't use any CPU features, but is limited like the other tokens.
+#[cfg(feature = "nightly")]
+pub(crate) fn portable_allowed() -> bool {
+    let max = max_rank();
+    max == UNLIMITED || 1 < max
+}
+
+impl AnyToken {
+    /// Returns the best token supported by the CPU.
+    ///
+    /// The CPU features are only detected the first time this is called.
+    #[inline]
+    pub fn new() -> Self {
+        match DETECTED.load(Ordering::Relaxed) {
+            0 => {
+                let token = Self::detect();
+                DETECTED.store(token.rank() + 1, Ordering::Relaxed);
+                token
+            }
+            rank => unsafe { Self::from_rank(rank - 1) },
+        }
+    }
+
+    /// Returns the name of the token's instruction set.
+    ///
+    /// This is the name accepted by the [`MAX_TOKEN_VAR`] environment variable, such as `"avx"`,
+    /// and matches the names reported by `#[dispatch(token, report)]`.
+    pub fn name(self) -> &'static str {
+        match self {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::X86V3(_) => "x86v3",
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx(_) => "avx",
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::X86V2(_) => "x86v2",
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Sse(_) => "sse",
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Sse2(_) => "sse2",
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            Self::Neon(_) => "neon",
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "nightly",
+            ))]
+            Self::Simd128(_) => "simd128",
+            #[cfg(feature = "nightly")]
+            Self::Portable(_) => "portable",
+            Self::Generic(_) => "generic",
+        }
+    }
+
+    /// Calls a function with this token.
+    ///
+    /// The function is called from a wrapper compiled with the token's CPU features enabled.
+    #[inline]
+    pub fn dispatch<F, Output>(self, f: F) -> Output
+    where
+        F: DispatchAll<Output>,
+    {
+        match self {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::X86V3(token) => unsafe { token.dispatch(f) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx(token) => unsafe { token.dispatch(f) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::X86V2(token) => unsafe { token.dispatch(f) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Sse(token) => unsafe { token.dispatch(f) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Sse2(token) => unsafe { token.dispatch(f) },
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            Self::Neon(token) => unsafe { token.dispatch(f) },
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "nightly",
+            ))]
+            Self::Simd128(token) => unsafe { token.dispatch(f) },
+            #[cfg(feature = "nightly")]
+            Self::Portable(token) => f.call(token),
+            Self::Generic(token) => f.call(token),
+        }
+    }
+
+    fn detect() -> Self {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Some(token) = X86V3::new() {
+                return Self::X86V3(token);
+            }
+            if let Some(token) = Avx::new() {
+                return Self::Avx(token);
+            }
+            if let Some(token) = X86V2::new() {
+                return Self::X86V2(token);
+            }
+            if let Some(token) = Sse::new() {
+                return Self::Sse(token);
+            }
+            if let Some(token) = Sse2::new() {
+                return Self::Sse2(token);
+            }
+        }
+        #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+        {
+            if let Some(token) = Neon::new() {
+                return Self::Neon(token);
+            }
+        }
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            feature = "nightly",
+        ))]
+        {
+            if let Some(token) = Simd128::new() {
+                return Self::Simd128(token);
+            }
+        }
+        #[cfg(feature = "nightly")]
+        {
+            if let Some(token) = Portable::new() {
+                return Self::Portable(token);
+            }
+        }
+        Self::Generic(Generic)
+    }
+
+    // Tokens are ranked by priority, starting from zero for the generic token.
+    fn rank(self) -> u8 {
+        match self {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::X86V3(_) => 6,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Avx(_) => 5,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::X86V2(_) => 4,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Sse(_) => 3,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::Sse2(_) => 2,
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            Self::Neon(_) => 2,
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "nightly",
+            ))]
+            Self::Simd128(_) => 2,
+            #[cfg(feature = "nightly")]
+            Self::Portable(_) => 1,
+            Self::Generic(_) => 0,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn rank_of(name: &str) -> Option<u8> {
+        match name {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "x86v3" => Some(6),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "avx" => Some(5),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "x86v2" => Some(4),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "sse" => Some(3),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "sse2" => Some(2),
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            "neon" => Some(2),
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "nightly",
+            ))]
+            "simd128" => Some(2),
+            #[cfg(feature = "nightly")]
+            "portable" => Some(1),
+            "generic" => Some(0),
+            _ => None,
+        }
+    }
+
+    // The rank of the lowest token that provides a CPU feature.
+    fn feature_rank(feature: &str) -> u8 {
+        match feature {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "sse" | "sse2" => 2,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "sse3" | "ssse3" | "sse4.1" => 3,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "sse4.2" | "popcnt" => 4,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "avx" => 5,
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            "avx2" | "bmi1" | "bmi2" | "f16c" | "fma" | "lzcnt" => 6,
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            "neon" => 2,
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "nightly",
+            ))]
+            "simd128" => 2,
+            _ => UNLIMITED,
+        }
+    }
+
+    // Safety: the rank must have been returned by `rank` for a detected token.
+    unsafe fn from_rank(rank: u8) -> Self {
+        match rank {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            6 => Self::X86V3(X86V3::new_unchecked()),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            5 => Self::Avx(Avx::new_unchecked()),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            4 => Self::X86V2(X86V2::new_unchecked()),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            3 => Self::Sse(Sse::new_unchecked()),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            2 => Self::Sse2(Sse2::new_unchecked()),
+            #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
+            2 => Self::Neon(Neon::new_unchecked()),
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                feature = "nightly",
+            ))]
+            2 => Self::Simd128(Simd128::new_unchecked()),
+            #[cfg(feature = "nightly")]
+            1 => Self::Portable(Portable::new_unchecked()),
+            _ => Self::Generic(Generic),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl From<Portable> for AnyToken {
+    #[inline]
+    fn from(token: Portable) -> Self {
+        Self::Portable(token)
+    }
+}
+
+impl From<Generic> for AnyToken {
+    #[inline]
+    fn from(token: Generic) -> Self {
+        Self::Generic(token)
+    }
+}
+
+impl Default for AnyToken {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,9 +1,4 @@
-use crate::{
-    arch::{arm::Neon, Token},
-    scalar::Scalar,
-    shim::{Shim2, Shim4, Shim8},
-    vector::{width, Native, Vector},
-};
+use crate::{arch::arm::Neon, vector::Vector};
 use num_complex::Complex;
 
 #[cfg(target_arch = "aarch64")]
@@ -11,14 +6,6 @@ use core::arch::aarch64::*;
 #[cfg(target_arch = "arm")]
 use core::arch::arm::*;
 
-impl Native<Neon> for Complex<f32> {
-    type Width = width::W2;
-}
-
-impl Native<Neon> for Complex<f64> {
-    type Width = width::W1;
-}
-
 /// A NEON vector of `Complex<f32>`s.
 ///
 /// Requires feature `"complex"`.
@@ -44,68 +31,46 @@ pub struct cf32x2(float32x4_t);
 #[allow(non_camel_case_types)]
 pub struct cf64x1(float64x2_t);
 
-impl Scalar<Neon, width::W1> for Complex<f32> {
-    type Vector = cf32x1;
-}
-
-impl Scalar<Neon, width::W2> for Complex<f32> {
-    type Vector = cf32x2;
-}
-
-impl Scalar<Neon, width::W4> for Complex<f32> {
-    type Vector = Shim2<cf32x2, Complex<f32>>;
-}
-
-impl Scalar<Neon, width::W8> for Complex<f32> {
-    type Vector = Shim4<cf32x2, Complex<f32>>;
+impl_scalar! {
+    Neon, Complex<f32>, native 2 {
+        1 => cf32x1,
+    }
+    2.. => cf32x2
 }
 
 #[cfg(target_arch = "arm")]
-impl Scalar<Neon, width::W1> for Complex<f64> {
-    type Vector = crate::arch::generic::cf64x1;
+impl_scalar! {
+    Neon, Complex<f64>, native 1 {}
+    1.. => crate::arch::generic::cf64x1
 }
 
 #[cfg(target_arch = "aarch64")]
-impl Scalar<Neon, width::W1> for Complex<f64> {
-    type Vector = cf64x1;
-}
-
-impl Scalar<Neon, width::W2> for Complex<f64> {
-    type Vector = Shim2<<Self as Scalar<Neon, width::W1>>::Vector, Complex<f64>>;
-}
-
-impl Scalar<Neon, width::W4> for Complex<f64> {
-    type Vector = Shim4<<Self as Scalar<Neon, width::W1>>::Vector, Complex<f64>>;
-}
-
-impl Scalar<Neon, width::W8> for Complex<f64> {
-    type Vector = Shim8<<Self as Scalar<Neon, width::W1>>::Vector, Complex<f64>>;
+impl_scalar! {
+    Neon, Complex<f64>, native 1 {}
+    1.. => cf64x1
 }
 
 arithmetic_ops! {
-    feature: Neon::new_unchecked(),
     for cf32x1:
-        add -> (vadd_f32),
-        sub -> (vsub_f32),
+        add -> (unsafe vadd_f32),
+        sub -> (unsafe vsub_f32),
         mul -> (),
         div -> ()
 }
 
 arithmetic_ops! {
-    feature: Neon::new_unchecked(),
     for cf32x2:
-        add -> (vaddq_f32),
-        sub -> (vsubq_f32),
+        add -> (unsafe vaddq_f32),
+        sub -> (unsafe vsubq_f32),
         mul -> (),
         div -> ()
 }
 
 #[cfg(target_arch = "aarch64")]
 arithmetic_ops! {
-    feature: Neon::new_unchecked(),
     for cf64x1:
-        add -> (vaddq_f64),
-        sub -> (vsubq_f64),
+        add -> (unsafe vaddq_f64),
+        sub -> (unsafe vsubq_f64),
         mul -> (),
         div -> ()
 }
@@ -152,6 +117,16 @@ as_slice! { cf32x2 }
 #[cfg(target_arch = "aarch64")]
 as_slice! { cf64x1 }
 
+impl crate::vector::PrefixSum for cf32x1 {}
+impl crate::vector::PrefixSum for cf32x2 {}
+#[cfg(target_arch = "aarch64")]
+impl crate::vector::PrefixSum for cf64x1 {}
+
+impl crate::vector::Horizontal for cf32x1 {}
+impl crate::vector::Horizontal for cf32x2 {}
+#[cfg(target_arch = "aarch64")]
+impl crate::vector::Horizontal for cf64x1 {}
+
 unsafe impl Vector for cf32x1 {
     type Scalar = Complex<f32>;
 
@@ -0,0 +1,90 @@
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "arm")]
+use core::arch::arm::*;
+
+use crate::{arch::arm::*, vector::Horizontal};
+
+macro_rules! implement {
+    {
+        $vector:ty, pairs -> $pairs:path, sum -> $sum:path, dot -> $dot:path
+    } => {
+        impl Horizontal for $vector {
+            #[inline]
+            fn horizontal_add_pairs(self, rhs: Self) -> Self {
+                Self(unsafe { $pairs(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn horizontal_sum(self) -> Self::Scalar {
+                unsafe { $sum(self.0) }
+            }
+
+            #[inline]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                unsafe { $dot(self.0, rhs.0) }
+            }
+        }
+    }
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn sum_f32x2(x: float32x2_t) -> f32 {
+    vget_lane_f32::<0>(vpadd_f32(x, x))
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn dot_f32x2(a: float32x2_t, b: float32x2_t) -> f32 {
+    sum_f32x2(vmul_f32(a, b))
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn add_pairs_f32x4(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    vpaddq_f32(a, b)
+}
+
+// 32-bit arm only has 64-bit pairwise adds, so add the halves of each input separately.
+#[cfg(target_arch = "arm")]
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn add_pairs_f32x4(a: float32x4_t, b: float32x4_t) -> float32x4_t {
+    vcombine_f32(
+        vpadd_f32(vget_low_f32(a), vget_high_f32(a)),
+        vpadd_f32(vget_low_f32(b), vget_high_f32(b)),
+    )
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn sum_f32x4(x: float32x4_t) -> f32 {
+    sum_f32x2(vpadd_f32(vget_low_f32(x), vget_high_f32(x)))
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn dot_f32x4(a: float32x4_t, b: float32x4_t) -> f32 {
+    sum_f32x4(vmulq_f32(a, b))
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn sum_f64x2(x: float64x2_t) -> f64 {
+    vgetq_lane_f64::<0>(vpaddq_f64(x, x))
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn dot_f64x2(a: float64x2_t, b: float64x2_t) -> f64 {
+    sum_f64x2(vmulq_f64(a, b))
+}
+
+implement! { f32x2, pairs -> vpadd_f32, sum -> sum_f32x2, dot -> dot_f32x2 }
+implement! { f32x4, pairs -> add_pairs_f32x4, sum -> sum_f32x4, dot -> dot_f32x4 }
+#[cfg(target_arch = "aarch64")]
+implement! { f64x2, pairs -> vpaddq_f64, sum -> sum_f64x2, dot -> dot_f64x2 }
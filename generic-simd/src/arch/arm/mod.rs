@@ -5,11 +5,13 @@ mod complex;
 #[cfg(feature = "complex")]
 pub use complex::*;
 
+mod horizontal;
+mod scan;
+
 use crate::{
-    arch::{generic, Token},
-    scalar::Scalar,
-    shim::{Shim2, Shim4, ShimToken},
-    vector::{width, Native, Vector},
+    arch::generic,
+    shim::{Shim, ShimToken},
+    vector::{Classify, Vector},
 };
 
 #[cfg(target_arch = "aarch64")]
@@ -21,15 +23,7 @@ use core::arch::arm::*;
 #[derive(Copy, Clone, Debug)]
 pub struct Neon(());
 
-impl_token! { Neon => "neon" }
-
-impl Native<Neon> for f32 {
-    type Width = width::W4;
-}
-
-impl Native<Neon> for f64 {
-    type Width = width::W2;
-}
+arch_token! { Neon => "neon" }
 
 /// A NEON vector of 2 `f32`s.
 #[derive(Clone, Copy, Debug)]
@@ -50,72 +44,131 @@ pub struct f32x4(float32x4_t);
 #[allow(non_camel_case_types)]
 pub struct f64x2(float64x2_t);
 
-impl Scalar<Neon, width::W1> for f32 {
-    type Vector = ShimToken<generic::f32x1, Self, Neon>;
+impl_scalar! {
+    Neon, f32, native 4 {
+        1 => ShimToken<generic::f32x1, Self, Neon>,
+        2 => f32x2,
+    }
+    4.. => f32x4
 }
 
-impl Scalar<Neon, width::W2> for f32 {
-    type Vector = f32x2;
+#[cfg(target_arch = "arm")]
+impl_scalar! {
+    Neon, f64, native 2 {
+        1 => ShimToken<generic::f64x1, Self, Neon>,
+    }
+    2.. => Shim<ShimToken<generic::f64x1, Self, Neon>, Self, 2>
 }
 
-impl Scalar<Neon, width::W4> for f32 {
-    type Vector = f32x4;
+#[cfg(target_arch = "aarch64")]
+impl_scalar! {
+    Neon, f64, native 2 {
+        1 => ShimToken<generic::f64x1, Self, Neon>,
+    }
+    2.. => f64x2
 }
 
-impl Scalar<Neon, width::W8> for f32 {
-    type Vector = Shim2<f32x4, Self>;
+impl_scalar! {
+    Neon, i32, native 4 {}
+    1.. => ShimToken<generic::i32x1, Self, Neon>
 }
 
-impl Scalar<Neon, width::W1> for f64 {
-    type Vector = ShimToken<generic::f64x1, Self, Neon>;
+impl_scalar! {
+    Neon, u32, native 4 {}
+    1.. => ShimToken<generic::u32x1, Self, Neon>
 }
 
-#[cfg(target_arch = "arm")]
-impl Scalar<Neon, width::W2> for f64 {
-    type Vector = Shim2<ShimToken<generic::f64x1, Self, Neon>, Self>;
+impl_scalar! {
+    Neon, u64, native 2 {}
+    1.. => ShimToken<generic::u64x1, Self, Neon>
 }
 
-#[cfg(target_arch = "aarch64")]
-impl Scalar<Neon, width::W2> for f64 {
-    type Vector = f64x2;
-}
+convert_lanes! { f32x2 => Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 2> }
+convert_lanes! { f32x2 => int Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 2> }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 2> => f32x2 }
+convert_lanes! { f32x4 => int Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 4> }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 4> => f32x4 }
 
-impl Scalar<Neon, width::W4> for f64 {
-    type Vector = Shim2<<Self as Scalar<Neon, width::W2>>::Vector, Self>;
-}
+#[cfg(target_arch = "arm")]
+convert_lanes! { f32x2 => Shim<ShimToken<generic::f64x1, f64, Neon>, f64, 2> }
+#[cfg(target_arch = "arm")]
+convert_lanes! { Shim<ShimToken<generic::f64x1, f64, Neon>, f64, 2> => f32x2 }
+#[cfg(target_arch = "arm")]
+convert_lanes! { f32x4 => Shim<ShimToken<generic::f64x1, f64, Neon>, f64, 4> }
+#[cfg(target_arch = "arm")]
+convert_lanes! { Shim<ShimToken<generic::f64x1, f64, Neon>, f64, 4> => f32x4 }
 
-impl Scalar<Neon, width::W8> for f64 {
-    type Vector = Shim4<<Self as Scalar<Neon, width::W2>>::Vector, Self>;
-}
+#[cfg(target_arch = "aarch64")]
+convert_lanes! { f32x2 => f64x2 }
+#[cfg(target_arch = "aarch64")]
+convert_lanes! { f64x2 => f32x2 }
+#[cfg(target_arch = "aarch64")]
+convert_lanes! { f64x2 => int Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 2> }
+#[cfg(target_arch = "aarch64")]
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Neon>, i32, 2> => f64x2 }
+#[cfg(target_arch = "aarch64")]
+convert_lanes! { f32x4 => Shim<f64x2, f64, 2> }
+#[cfg(target_arch = "aarch64")]
+convert_lanes! { Shim<f64x2, f64, 2> => f32x4 }
 
 arithmetic_ops! {
-    feature: Neon::new_unchecked(),
     for f32x2:
-        add -> (vadd_f32),
-        sub -> (vsub_f32),
-        mul -> (vmul_f32),
+        add -> (unsafe vadd_f32),
+        sub -> (unsafe vsub_f32),
+        mul -> (unsafe vmul_f32),
         div -> ()
 }
 
 arithmetic_ops! {
-    feature: Neon::new_unchecked(),
     for f32x4:
-        add -> (vaddq_f32),
-        sub -> (vsubq_f32),
-        mul -> (vmulq_f32),
+        add -> (unsafe vaddq_f32),
+        sub -> (unsafe vsubq_f32),
+        mul -> (unsafe vmulq_f32),
         div -> ()
 }
 
 #[cfg(target_arch = "aarch64")]
 arithmetic_ops! {
-    feature: Neon::new_unchecked(),
     for f64x2:
-        add -> (vaddq_f64),
-        sub -> (vsubq_f64),
-        mul -> (vmulq_f64),
+        add -> (unsafe vaddq_f64),
+        sub -> (unsafe vsubq_f64),
+        mul -> (unsafe vmulq_f64),
         div -> ()
 }
 
+bitwise_ops! {
+    for f32x2:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+bitwise_ops! {
+    for f32x4:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+#[cfg(target_arch = "aarch64")]
+bitwise_ops! {
+    for f64x2:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+impl Classify for f32x2 {}
+impl Classify for f32x4 {}
+#[cfg(target_arch = "aarch64")]
+impl Classify for f64x2 {}
+
 impl core::ops::Neg for f32x2 {
     type Output = Self;
 
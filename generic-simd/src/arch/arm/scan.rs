@@ -0,0 +1,70 @@
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "arm")]
+use core::arch::arm::*;
+
+use crate::{arch::arm::*, vector::PrefixSum};
+
+macro_rules! implement {
+    {
+        $vector:ty, $inclusive:ident, $exclusive:ident
+    } => {
+        impl PrefixSum for $vector {
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                Self(unsafe { $inclusive(self.0) })
+            }
+
+            #[inline]
+            fn exclusive_prefix_sum(self) -> Self {
+                Self(unsafe { $inclusive($exclusive(self.0)) })
+            }
+        }
+    }
+}
+
+// Extracting from a zero vector concatenated with `x` shifts lanes up, shifting in zeros.
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn prefix_sum_f32x2(x: float32x2_t) -> float32x2_t {
+    vadd_f32(x, shift_lane_f32x2(x))
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn shift_lane_f32x2(x: float32x2_t) -> float32x2_t {
+    vext_f32::<1>(vdup_n_f32(0.), x)
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn prefix_sum_f32x4(x: float32x4_t) -> float32x4_t {
+    let zero = vdupq_n_f32(0.);
+    let x = vaddq_f32(x, vextq_f32::<3>(zero, x));
+    vaddq_f32(x, vextq_f32::<2>(zero, x))
+}
+
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn shift_lane_f32x4(x: float32x4_t) -> float32x4_t {
+    vextq_f32::<3>(vdupq_n_f32(0.), x)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn prefix_sum_f64x2(x: float64x2_t) -> float64x2_t {
+    vaddq_f64(x, shift_lane_f64x2(x))
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+#[inline]
+unsafe fn shift_lane_f64x2(x: float64x2_t) -> float64x2_t {
+    vextq_f64::<1>(vdupq_n_f64(0.), x)
+}
+
+implement! { f32x2, prefix_sum_f32x2, shift_lane_f32x2 }
+implement! { f32x4, prefix_sum_f32x4, shift_lane_f32x4 }
+#[cfg(target_arch = "aarch64")]
+implement! { f64x2, prefix_sum_f64x2, shift_lane_f64x2 }
@@ -0,0 +1,108 @@
+use crate::arch::{generic::Generic, Token};
+
+unsafe impl<A, B> Token for (A, B)
+where
+    A: Token,
+    B: Token,
+{
+    #[inline]
+    fn new() -> Option<Self> {
+        Some((A::new()?, B::new()?))
+    }
+
+    #[inline]
+    unsafe fn new_unchecked() -> Self {
+        (A::new_unchecked(), B::new_unchecked())
+    }
+}
+
+unsafe impl<A, B, C> Token for (A, B, C)
+where
+    A: Token,
+    B: Token,
+    C: Token,
+{
+    #[inline]
+    fn new() -> Option<Self> {
+        Some((A::new()?, B::new()?, C::new()?))
+    }
+
+    #[inline]
+    unsafe fn new_unchecked() -> Self {
+        (A::new_unchecked(), B::new_unchecked(), C::new_unchecked())
+    }
+}
+
+impl<A, B> From<(A, B)> for Generic
+where
+    A: Token,
+    B: Token,
+{
+    #[inline]
+    fn from(_: (A, B)) -> Self {
+        Self
+    }
+}
+
+impl<A, B, C> From<(A, B, C)> for Generic
+where
+    A: Token,
+    B: Token,
+    C: Token,
+{
+    #[inline]
+    fn from(_: (A, B, C)) -> Self {
+        Self
+    }
+}
+
+// Conversions from composite tokens to their components can't be implemented generically without
+// overlapping when a token is repeated, so they're implemented for each combination of distinct
+// tokens.
+#[allow(unused_macros)]
+macro_rules! composite_tokens {
+    { $($token:ty),* } => {
+        composite_tokens! { @subsets [$($token),*] }
+    };
+    { @subsets [] } => {};
+    { @subsets [$first:ty $(, $rest:ty)*] } => {
+        composite_tokens! { @pairs $first [$($rest),*] }
+        composite_tokens! { @subsets [$($rest),*] }
+    };
+    { @pairs $first:ty [] } => {};
+    { @pairs $first:ty [$second:ty $(, $rest:ty)*] } => {
+        composite_tokens! { @from ($first, $second) => $first, $second }
+        composite_tokens! { @from ($second, $first) => $first, $second }
+        $(composite_tokens! { @triple $first, $second, $rest })*
+        composite_tokens! { @pairs $first [$($rest),*] }
+    };
+    { @triple $a:ty, $b:ty, $c:ty } => {
+        composite_tokens! { @from ($a, $b, $c) => $a, $b, $c }
+        composite_tokens! { @from ($a, $c, $b) => $a, $b, $c }
+        composite_tokens! { @from ($b, $a, $c) => $a, $b, $c }
+        composite_tokens! { @from ($b, $c, $a) => $a, $b, $c }
+        composite_tokens! { @from ($c, $a, $b) => $a, $b, $c }
+        composite_tokens! { @from ($c, $b, $a) => $a, $b, $c }
+    };
+    { @from $composite:ty => $($component:ty),+ } => {
+        $(
+            impl From<$composite> for $component {
+                #[inline]
+                fn from(_: $composite) -> Self {
+                    unsafe { Self::new_unchecked() }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+composite_tokens! {
+    crate::arch::x86::X86V3,
+    crate::arch::x86::X86V2,
+    crate::arch::x86::Avx,
+    crate::arch::x86::Sse,
+    crate::arch::x86::Sse2,
+    crate::arch::x86::Fma,
+    crate::arch::x86::F16c
+}
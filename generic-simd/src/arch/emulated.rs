@@ -0,0 +1,324 @@
+//! Emulated vector types of any width for any platform.
+
+use crate::{
+    arch::{generic, Token},
+    scalar::Scalar,
+    shim::ShimToken,
+    vector::{width, Classify, Horizontal, Native, PrefixSum, Vector},
+};
+use core::marker::PhantomData;
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+/// Emulated instruction set token, with a native width of `W`.
+///
+/// The vectors are implemented with portable code, like those of the [`Generic`] token, but the
+/// native width can be chosen.
+/// This is intended for testing width-dependent code, such as remainder handling, on any CPU.
+///
+/// ```
+/// use generic_simd::{
+///     arch::{emulated::Emulated, Token},
+///     scalar::ScalarExt,
+///     vector::width,
+/// };
+///
+/// let token = Emulated::<width::W4>::new().unwrap();
+/// let x = 1f32.splat_native(token);
+/// assert_eq!(x.len(), 4);
+/// ```
+///
+/// [`Generic`]: super::generic::Generic
+pub struct Emulated<W>(PhantomData<W>);
+
+impl<W> Clone for Emulated<W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<W> Copy for Emulated<W> {}
+
+impl<W: width::Width> core::fmt::Debug for Emulated<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Emulated<{}>", W::VALUE)
+    }
+}
+
+impl<W> Emulated<W> {
+    /// Returns the token, which is always supported.
+    #[inline]
+    pub fn get() -> Self {
+        Self(PhantomData)
+    }
+}
+
+unsafe impl<const N: usize> Token for Emulated<width::Lanes<N>> {
+    #[inline]
+    fn new() -> Option<Self> {
+        Some(Self::get())
+    }
+
+    #[inline]
+    unsafe fn new_unchecked() -> Self {
+        Self::get()
+    }
+}
+
+impl<W> From<Emulated<W>> for generic::Generic {
+    #[inline]
+    fn from(_: Emulated<W>) -> Self {
+        Self
+    }
+}
+
+/// Scalars that can be emulated in vectors of `N` lanes.
+///
+/// The alignment of `Align` is the size of the vector.
+pub trait ArrayScalar<const N: usize>: Copy {
+    /// A type aligned like a native vector of `N` lanes of this scalar.
+    type Align: Copy + core::fmt::Debug;
+}
+
+// Zero-sized types, public but unnameable, that only carry an alignment.
+mod align {
+    macro_rules! align {
+        { $($name:ident, $align:literal;)* } => {
+            $(
+            #[derive(Clone, Copy, Debug)]
+            #[repr(align($align))]
+            pub struct $name;
+            )*
+        }
+    }
+
+    align! {
+        Align4, 4;
+        Align8, 8;
+        Align16, 16;
+        Align32, 32;
+        Align64, 64;
+        Align128, 128;
+        Align256, 256;
+        Align512, 512;
+    }
+}
+
+macro_rules! array_scalar {
+    { $scalar:ty, $($lanes:literal => $align:ident),* } => {
+        $(
+        impl ArrayScalar<$lanes> for $scalar {
+            type Align = align::$align;
+        }
+        )*
+    }
+}
+
+array_scalar! { f32, 1 => Align4, 2 => Align8, 4 => Align16, 8 => Align32, 16 => Align64, 32 => Align128 }
+array_scalar! { i32, 1 => Align4, 2 => Align8, 4 => Align16, 8 => Align32, 16 => Align64, 32 => Align128 }
+array_scalar! { u32, 1 => Align4, 2 => Align8, 4 => Align16, 8 => Align32, 16 => Align64, 32 => Align128 }
+array_scalar! { f64, 1 => Align8, 2 => Align16, 4 => Align32, 8 => Align64, 16 => Align128, 32 => Align256 }
+array_scalar! { u64, 1 => Align8, 2 => Align16, 4 => Align32, 8 => Align64, 16 => Align128, 32 => Align256 }
+#[cfg(feature = "complex")]
+array_scalar! { Complex<f32>, 1 => Align8, 2 => Align16, 4 => Align32, 8 => Align64, 16 => Align128, 32 => Align256 }
+#[cfg(feature = "complex")]
+array_scalar! { Complex<f64>, 1 => Align16, 2 => Align32, 4 => Align64, 8 => Align128, 16 => Align256, 32 => Align512 }
+
+/// An emulated vector of `N` lanes.
+///
+/// Emulated vectors are aligned to their size, like native vectors, so
+/// [`align`](crate::slice::Slice::align) splits unaligned slices as it would on real hardware.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct Array<T: ArrayScalar<N>, const N: usize>([T; N], [T::Align; 0]);
+
+macro_rules! implement_array {
+    {
+        $vector:ty, $scalar:ty, $lanes:literal
+    } => {
+        arithmetic_ops! {
+            for $vector:
+                add -> (),
+                sub -> (),
+                mul -> (),
+                div -> ()
+        }
+
+        as_slice! { $vector }
+
+        impl PrefixSum for $vector {}
+
+        impl Horizontal for $vector {}
+
+        impl From<[$scalar; $lanes]> for $vector {
+            #[inline]
+            fn from(from: [$scalar; $lanes]) -> Self {
+                Self(from, [])
+            }
+        }
+
+        unsafe impl Vector for $vector {
+            type Scalar = $scalar;
+
+            type Token = generic::Generic;
+
+            type Width = width::Lanes<$lanes>;
+
+            // The array alone isn't aligned like the vector.
+            type Underlying = Self;
+
+            #[inline]
+            fn zeroed(_: Self::Token) -> Self {
+                Self([<$scalar>::default(); $lanes], [])
+            }
+
+            #[inline]
+            fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+                Self([from; $lanes], [])
+            }
+        }
+    };
+    {
+        @neg $vector:ty, $neg:path
+    } => {
+        impl core::ops::Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(mut self) -> Self {
+                for x in self.0.iter_mut() {
+                    *x = $neg(*x);
+                }
+                self
+            }
+        }
+    };
+    {
+        @bitwise $vector:ty
+    } => {
+        bitwise_ops! {
+            for $vector:
+                and -> (),
+                or -> (),
+                xor -> (),
+                not -> (),
+                and_not -> ()
+        }
+    };
+    {
+        @lanes $lanes:literal
+    } => {
+        implement_array! { Array<f32, $lanes>, f32, $lanes }
+        implement_array! { Array<f64, $lanes>, f64, $lanes }
+        implement_array! { Array<i32, $lanes>, i32, $lanes }
+        implement_array! { Array<u32, $lanes>, u32, $lanes }
+        implement_array! { Array<u64, $lanes>, u64, $lanes }
+
+        implement_array! { @neg Array<f32, $lanes>, core::ops::Neg::neg }
+        implement_array! { @neg Array<f64, $lanes>, core::ops::Neg::neg }
+        implement_array! { @neg Array<i32, $lanes>, i32::wrapping_neg }
+
+        implement_array! { @bitwise Array<f32, $lanes> }
+        implement_array! { @bitwise Array<f64, $lanes> }
+        implement_array! { @bitwise Array<i32, $lanes> }
+        implement_array! { @bitwise Array<u32, $lanes> }
+        implement_array! { @bitwise Array<u64, $lanes> }
+
+        shift_ops! { for Array<i32, $lanes>: shl -> (), shr -> () }
+        shift_ops! { for Array<u32, $lanes>: shl -> (), shr -> () }
+        shift_ops! { for Array<u64, $lanes>: shl -> (), shr -> () }
+
+        impl Classify for Array<f32, $lanes> {}
+        impl Classify for Array<f64, $lanes> {}
+
+        convert_lanes! { Array<f32, $lanes> => Array<f64, $lanes> }
+        convert_lanes! { Array<f64, $lanes> => Array<f32, $lanes> }
+        convert_lanes! { Array<i32, $lanes> => Array<f32, $lanes> }
+        convert_lanes! { Array<i32, $lanes> => Array<f64, $lanes> }
+        convert_lanes! { Array<f32, $lanes> => int Array<i32, $lanes> }
+        convert_lanes! { Array<f64, $lanes> => int Array<i32, $lanes> }
+
+        #[cfg(feature = "complex")]
+        implement_array! { @complex Array<Complex<f32>, $lanes>, f32, $lanes }
+        #[cfg(feature = "complex")]
+        implement_array! { @complex Array<Complex<f64>, $lanes>, f64, $lanes }
+    };
+    {
+        @complex $vector:ty, $real:ty, $lanes:literal
+    } => {
+        implement_array! { $vector, Complex<$real>, $lanes }
+        implement_array! { @neg $vector, core::ops::Neg::neg }
+
+        impl crate::vector::Complex for $vector {
+            type RealScalar = $real;
+
+            #[inline]
+            fn conj(mut self) -> Self {
+                for x in self.0.iter_mut() {
+                    *x = x.conj();
+                }
+                self
+            }
+
+            #[inline]
+            fn mul_i(mut self) -> Self {
+                for x in self.0.iter_mut() {
+                    *x = Complex::new(-x.im, x.re);
+                }
+                self
+            }
+
+            #[inline]
+            fn mul_neg_i(mut self) -> Self {
+                for x in self.0.iter_mut() {
+                    *x = Complex::new(x.im, -x.re);
+                }
+                self
+            }
+        }
+    };
+}
+
+implement_array! { @lanes 1 }
+implement_array! { @lanes 2 }
+implement_array! { @lanes 4 }
+implement_array! { @lanes 8 }
+implement_array! { @lanes 16 }
+implement_array! { @lanes 32 }
+
+macro_rules! implement {
+    {
+        $scalar:ty
+    } => {
+        impl<const N: usize> Native<Emulated<width::Lanes<N>>> for $scalar {
+            type Width = width::Lanes<N>;
+        }
+
+        implement! { @width $scalar, 1, W1 }
+        implement! { @width $scalar, 2, W2 }
+        implement! { @width $scalar, 4, W4 }
+        implement! { @width $scalar, 8, W8 }
+        implement! { @width $scalar, 16, W16 }
+        implement! { @width $scalar, 32, W32 }
+    };
+    {
+        @width $scalar:ty, $lanes:literal, $width:ident
+    } => {
+        impl<const N: usize> Scalar<Emulated<width::Lanes<N>>, width::$width> for $scalar {
+            type Vector = ShimToken<Array<$scalar, $lanes>, $scalar, Emulated<width::Lanes<N>>>;
+        }
+    };
+}
+
+implement! { f32 }
+implement! { f64 }
+implement! { i32 }
+implement! { u32 }
+implement! { u64 }
+
+#[cfg(feature = "complex")]
+implement! { Complex<f32> }
+#[cfg(feature = "complex")]
+implement! { Complex<f64> }
@@ -2,9 +2,8 @@
 
 use crate::{
     arch::Token,
-    scalar::Scalar,
-    shim::{Shim2, Shim4, Shim8},
-    vector::{width, Native, Vector},
+    shim::Shim,
+    vector::{width, Classify, Horizontal, PrefixSum, Vector},
 };
 
 #[cfg(feature = "complex")]
@@ -14,6 +13,14 @@ use num_complex::Complex;
 #[derive(Copy, Clone, Debug)]
 pub struct Generic;
 
+impl Generic {
+    /// Returns the token, which is always supported.
+    #[inline]
+    pub fn get() -> Self {
+        Self
+    }
+}
+
 unsafe impl Token for Generic {
     #[inline]
     fn new() -> Option<Self> {
@@ -38,6 +45,40 @@ pub struct f32x1(f32);
 #[allow(non_camel_case_types)]
 pub struct f64x1(f64);
 
+/// A generic vector of four `f32`s.
+///
+/// Operations are loops over the lanes, which the compiler may autovectorize.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f32x4([f32; 4]);
+
+/// A generic vector of two `f64`s.
+///
+/// Operations are loops over the lanes, which the compiler may autovectorize.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f64x2([f64; 2]);
+
+/// A generic vector of one `i32`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x1(i32);
+
+/// A generic vector of one `u32`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x1(u32);
+
+/// A generic vector of one `u64`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x1(u64);
+
 /// A generic vector of one `Complex<f32>`.
 ///
 /// Requires feature `"complex"`.
@@ -56,28 +97,110 @@ pub struct cf32x1(Complex<f32>);
 #[allow(non_camel_case_types)]
 pub struct cf64x1(Complex<f64>);
 
+impl_scalar! {
+    Generic, i32, native 1 {}
+    1.. => i32x1
+}
+
+impl_scalar! {
+    Generic, u32, native 1 {}
+    1.. => u32x1
+}
+
+impl_scalar! {
+    Generic, u64, native 1 {}
+    1.. => u64x1
+}
+
+#[cfg(feature = "complex")]
+impl_scalar! {
+    Generic, Complex<f32>, native 1 {}
+    1.. => cf32x1
+}
+
+#[cfg(feature = "complex")]
+impl_scalar! {
+    Generic, Complex<f64>, native 1 {}
+    1.. => cf64x1
+}
+
+impl_scalar! {
+    Generic, f32, native 4 {
+        1 => f32x1,
+        2 => Shim<f32x1, f32, 2>,
+    }
+    4.. => f32x4
+}
+
+impl_scalar! {
+    Generic, f64, native 2 {
+        1 => f64x1,
+    }
+    2.. => f64x2
+}
+
 macro_rules! implement {
     {
         $vector:ty, $scalar:ty
     } => {
-        impl Scalar<Generic, width::W1> for $scalar {
-            type Vector = $vector;
-        }
+        implement! { $vector, $scalar, (), (), (), (), core::ops::Neg::neg }
+    };
+    {
+        $vector:ty, $scalar:ty, $add:tt, $sub:tt, $mul:tt, $div:tt, $neg:path
+    } => {
+        implement! { $vector, $scalar, $add, $sub, $mul, $div }
 
-        impl Scalar<Generic, width::W2> for $scalar {
-            type Vector = Shim2<$vector, $scalar>;
-        }
+        impl core::ops::Neg for $vector {
+            type Output = Self;
 
-        impl Scalar<Generic, width::W4> for $scalar {
-            type Vector = Shim4<$vector, $scalar>;
+            #[inline]
+            fn neg(self) -> Self {
+                Self($neg(self.0))
+            }
+        }
+    };
+    {
+        $vector:ty, $scalar:ty, $add:tt, $sub:tt, $mul:tt, $div:tt
+    } => {
+        arithmetic_ops! {
+            for $vector:
+                add -> $add,
+                sub -> $sub,
+                mul -> $mul,
+                div -> $div
         }
 
-        impl Scalar<Generic, width::W8> for $scalar {
-            type Vector = Shim8<$vector, $scalar>;
+        as_slice! { $vector }
+
+        impl PrefixSum for $vector {}
+
+        impl Horizontal for $vector {}
+
+        impl From<[$scalar; 1]> for $vector {
+            #[inline]
+            fn from(from: [$scalar; 1]) -> Self {
+                Self(from[0])
+            }
         }
 
-        impl Native<Generic> for $scalar {
-            type Width = width::W1;
+        unsafe impl Vector for $vector {
+            type Scalar = $scalar;
+
+            type Token = Generic;
+
+            type Width = crate::vector::width::W1;
+
+            type Underlying = $scalar;
+
+            #[inline]
+            fn zeroed(_: Self::Token) -> Self {
+                Self(<$scalar>::default())
+            }
+
+            #[inline]
+            fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+                Self(from)
+            }
         }
     }
 }
@@ -85,17 +208,14 @@ macro_rules! implement {
 implement! { f32x1, f32 }
 implement! { f64x1, f64 }
 
-#[cfg(feature = "complex")]
-implement! { cf32x1, Complex<f32> }
-#[cfg(feature = "complex")]
-implement! { cf64x1, Complex<f64> }
+impl Classify for f32x1 {}
+impl Classify for f64x1 {}
 
-macro_rules! implement {
+macro_rules! implement_array {
     {
-        $vector:ty, $scalar:ty
+        $vector:ty, $scalar:ty, $width:ident, $lanes:literal
     } => {
         arithmetic_ops! {
-            feature: Generic::new_unchecked(),
             for $vector:
                 add -> (),
                 sub -> (),
@@ -103,47 +223,430 @@ macro_rules! implement {
                 div -> ()
         }
 
+        bitwise_ops! {
+            for $vector:
+                and -> (),
+                or -> (),
+                xor -> (),
+                not -> (),
+                and_not -> ()
+        }
+
         impl core::ops::Neg for $vector {
             type Output = Self;
 
             #[inline]
-            fn neg(self) -> Self {
-                Self(-self.0)
+            fn neg(mut self) -> Self {
+                for x in self.0.iter_mut() {
+                    *x = -*x;
+                }
+                self
             }
         }
 
         as_slice! { $vector }
 
+        impl PrefixSum for $vector {}
+
+        impl Horizontal for $vector {}
+
+        impl Classify for $vector {}
+
+        impl From<[$scalar; $lanes]> for $vector {
+            #[inline]
+            fn from(from: [$scalar; $lanes]) -> Self {
+                Self(from)
+            }
+        }
+
         unsafe impl Vector for $vector {
             type Scalar = $scalar;
 
             type Token = Generic;
 
-            type Width = crate::vector::width::W1;
+            type Width = width::$width;
 
-            type Underlying = $scalar;
+            type Underlying = [$scalar; $lanes];
 
             #[inline]
             fn zeroed(_: Self::Token) -> Self {
-                Self(<$scalar>::default())
+                Self(Default::default())
             }
 
             #[inline]
             fn splat(_: Self::Token, from: Self::Scalar) -> Self {
-                Self(from)
+                Self([from; $lanes])
             }
         }
     }
 }
 
-implement! { f32x1, f32 }
-implement! { f64x1, f64 }
+implement_array! { f32x4, f32, W4, 4 }
+implement_array! { f64x2, f64, W2, 2 }
+
+// Integer vectors wrap on overflow, like the native instruction sets.
+implement! {
+    i32x1, i32,
+    (i32::wrapping_add),
+    (i32::wrapping_sub),
+    (i32::wrapping_mul),
+    (i32::wrapping_div),
+    i32::wrapping_neg
+}
+implement! {
+    u32x1, u32,
+    (u32::wrapping_add),
+    (u32::wrapping_sub),
+    (u32::wrapping_mul),
+    (u32::wrapping_div)
+}
+implement! {
+    u64x1, u64,
+    (u64::wrapping_add),
+    (u64::wrapping_sub),
+    (u64::wrapping_mul),
+    (u64::wrapping_div)
+}
 
 #[cfg(feature = "complex")]
 implement! { cf32x1, Complex<f32> }
 #[cfg(feature = "complex")]
 implement! { cf64x1, Complex<f64> }
 
+bitwise_ops! {
+    for i32x1:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+shift_ops! {
+    for i32x1:
+        shl -> (),
+        shr -> ()
+}
+
+bitwise_ops! {
+    for u32x1:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+shift_ops! {
+    for u32x1:
+        shl -> (),
+        shr -> ()
+}
+
+bitwise_ops! {
+    for u64x1:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+shift_ops! {
+    for u64x1:
+        shl -> (),
+        shr -> ()
+}
+
+bitwise_ops! {
+    for f32x1:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+bitwise_ops! {
+    for f64x1:
+        and -> (),
+        or -> (),
+        xor -> (),
+        not -> (),
+        and_not -> ()
+}
+
+/// Scalar bit representations, as used by [`Bitwise`](../../vector/trait.Bitwise.html).
+#[doc(hidden)]
+pub trait ScalarBits: Copy {
+    type Bits: core::ops::BitAnd<Output = Self::Bits>
+        + core::ops::BitOr<Output = Self::Bits>
+        + core::ops::BitXor<Output = Self::Bits>
+        + core::ops::Not<Output = Self::Bits>;
+
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+macro_rules! scalar_bits {
+    {
+        $scalar:ty, $bits:ty
+    } => {
+        impl ScalarBits for $scalar {
+            type Bits = $bits;
+
+            #[inline]
+            fn to_bits(self) -> $bits {
+                <$scalar>::to_bits(self)
+            }
+
+            #[inline]
+            fn from_bits(bits: $bits) -> Self {
+                <$scalar>::from_bits(bits)
+            }
+        }
+    }
+}
+
+scalar_bits! { f32, u32 }
+scalar_bits! { f64, u64 }
+
+// Integers are their own bit representation.
+macro_rules! int_bits {
+    {
+        $scalar:ty
+    } => {
+        impl ScalarBits for $scalar {
+            type Bits = $scalar;
+
+            #[inline]
+            fn to_bits(self) -> $scalar {
+                self
+            }
+
+            #[inline]
+            fn from_bits(bits: $scalar) -> Self {
+                bits
+            }
+        }
+    }
+}
+
+int_bits! { i32 }
+int_bits! { u32 }
+int_bits! { u64 }
+
+/// Scalar shifts, as used by [`Shift`](../../vector/trait.Shift.html).
+///
+/// Shifting by at least the number of bits saturates, like the native instruction sets.
+#[doc(hidden)]
+pub trait ScalarShift: Copy {
+    fn shl(self, rhs: u32) -> Self;
+    fn shr(self, rhs: u32) -> Self;
+}
+
+macro_rules! scalar_shift {
+    {
+        $scalar:ty
+    } => {
+        impl ScalarShift for $scalar {
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                self.checked_shl(rhs).unwrap_or(0)
+            }
+
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                // Shifting by one less than the number of bits, and then by one more, fills with
+                // the sign bit of signed integers and with zero for unsigned integers.
+                const LAST_BIT: u32 = (core::mem::size_of::<$scalar>() * 8 - 1) as u32;
+                self.checked_shr(rhs).unwrap_or((self >> LAST_BIT) >> 1)
+            }
+        }
+    }
+}
+
+scalar_shift! { i32 }
+scalar_shift! { u32 }
+scalar_shift! { u64 }
+
+/// Scalar arithmetic, as used by vectors that operate on each lane.
+///
+/// Integer operations wrap on overflow, like the native instruction sets.
+#[doc(hidden)]
+pub trait ScalarArithmetic: Copy {
+    fn add(self, rhs: Self) -> Self;
+    fn sub(self, rhs: Self) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+    fn div(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = Self::add(*self, rhs);
+    }
+
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = Self::sub(*self, rhs);
+    }
+
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = Self::mul(*self, rhs);
+    }
+
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = Self::div(*self, rhs);
+    }
+}
+
+macro_rules! scalar_arithmetic {
+    {
+        $scalar:ty, $add:expr, $sub:expr, $mul:expr, $div:expr
+    } => {
+        impl ScalarArithmetic for $scalar {
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $add(self, rhs)
+            }
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                $sub(self, rhs)
+            }
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                $mul(self, rhs)
+            }
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                $div(self, rhs)
+            }
+        }
+    };
+    {
+        float $scalar:ty
+    } => {
+        scalar_arithmetic! {
+            $scalar,
+            core::ops::Add::add,
+            core::ops::Sub::sub,
+            core::ops::Mul::mul,
+            core::ops::Div::div
+        }
+    };
+    {
+        int $scalar:ty
+    } => {
+        scalar_arithmetic! {
+            $scalar,
+            <$scalar>::wrapping_add,
+            <$scalar>::wrapping_sub,
+            <$scalar>::wrapping_mul,
+            <$scalar>::wrapping_div
+        }
+    };
+}
+
+scalar_arithmetic! { float f32 }
+scalar_arithmetic! { float f64 }
+scalar_arithmetic! { int i32 }
+scalar_arithmetic! { int u32 }
+scalar_arithmetic! { int u64 }
+#[cfg(feature = "complex")]
+scalar_arithmetic! { float Complex<f32> }
+#[cfg(feature = "complex")]
+scalar_arithmetic! { float Complex<f64> }
+
+/// Scalar numeric conversions, as used by [`Convert`](../../vector/trait.Convert.html).
+pub(crate) trait ConvertScalar<To> {
+    fn convert(self) -> To;
+}
+
+/// Scalar numeric conversions, as used by [`ConvertToInt`](../../vector/trait.ConvertToInt.html).
+pub(crate) trait ConvertScalarToInt<To> {
+    fn convert_truncate(self) -> To;
+    fn convert_round(self) -> To;
+    fn convert_saturate(self) -> To;
+}
+
+macro_rules! convert_scalar {
+    {
+        $from:ty => int i32
+    } => {
+        impl ConvertScalarToInt<i32> for $from {
+            #[inline]
+            fn convert_truncate(self) -> i32 {
+                self.convert_saturate()
+            }
+
+            #[inline]
+            fn convert_round(self) -> i32 {
+                let truncated = self.convert_saturate();
+                let fraction = self - truncated as $from;
+                let odd = truncated & 1 == 1;
+                if fraction > 0.5 || (fraction == 0.5 && odd) {
+                    truncated.saturating_add(1)
+                } else if fraction < -0.5 || (fraction == -0.5 && odd) {
+                    truncated.saturating_sub(1)
+                } else {
+                    truncated
+                }
+            }
+
+            #[inline]
+            fn convert_saturate(self) -> i32 {
+                // Out of range casts are undefined behavior prior to Rust 1.45.
+                if self.is_nan() {
+                    0
+                } else if self >= 2147483648. {
+                    2147483647
+                } else if self < -2147483648. {
+                    -2147483648
+                } else {
+                    self as i32
+                }
+            }
+        }
+    };
+    {
+        $from:ty => $to:ty
+    } => {
+        impl ConvertScalar<$to> for $from {
+            #[inline]
+            fn convert(self) -> $to {
+                self as $to
+            }
+        }
+    };
+}
+
+convert_scalar! { f32 => f64 }
+convert_scalar! { f64 => f32 }
+convert_scalar! { i32 => f32 }
+convert_scalar! { i32 => f64 }
+convert_scalar! { f32 => int i32 }
+convert_scalar! { f64 => int i32 }
+
+convert_lanes! { f32x1 => f64x1 }
+convert_lanes! { f64x1 => f32x1 }
+convert_lanes! { i32x1 => f32x1 }
+convert_lanes! { i32x1 => f64x1 }
+convert_lanes! { f32x1 => int i32x1 }
+convert_lanes! { f64x1 => int i32x1 }
+
+convert_lanes! { Shim<f32x1, f32, 2> => f64x2 }
+convert_lanes! { f64x2 => Shim<f32x1, f32, 2> }
+convert_lanes! { f64x2 => int Shim<i32x1, i32, 2> }
+convert_lanes! { Shim<i32x1, i32, 2> => f64x2 }
+convert_lanes! { f32x4 => Shim<f64x2, f64, 2> }
+convert_lanes! { Shim<f64x2, f64, 2> => f32x4 }
+convert_lanes! { f32x4 => int Shim<i32x1, i32, 4> }
+convert_lanes! { Shim<i32x1, i32, 4> => f32x4 }
+
 #[cfg(feature = "complex")]
 macro_rules! implement_complex {
     {
@@ -2,6 +2,28 @@
 
 /// Indicates support for a particular CPU feature.
 ///
+/// # Composite tokens
+/// Tuples of two or three tokens are also tokens, supported when every component is supported.
+/// On x86, composite tokens convert into each of their components, so a function can require
+/// several CPU features through a single token:
+/// ```
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// # {
+/// use generic_simd::arch::{
+///     x86::{Avx, Fma},
+///     Token,
+/// };
+///
+/// fn requires_fma<T: Token + Into<Avx> + Into<Fma>>(token: T) -> (Avx, Fma) {
+///     (token.into(), token.into())
+/// }
+///
+/// if let Some(token) = <(Avx, Fma)>::new() {
+///     requires_fma(token);
+/// }
+/// # }
+/// ```
+///
 /// # Safety
 /// Implementing `Token` for a type indicates that the type is only constructible when the
 /// associated CPU features are supported.
@@ -17,13 +39,68 @@ pub unsafe trait Token: Copy + From<Self> + Into<Self> {
     unsafe fn new_unchecked() -> Self;
 }
 
-#[allow(unused_macros)]
+/// Implements [`Token`] for a type.
+///
+/// The type must be a tuple struct containing only `()`, such as `struct MyToken(());`, so that
+/// it can't be constructed outside of its module.
+/// The token is supported when the listed CPU features are detected, and is limited by
+/// [`set_max_token`] like the built-in tokens.
+/// When the features are enabled at compile time, the token also has a safe `get` function.
+/// A token without any features, such as for vectors implemented in portable code, is always
+/// supported and is implemented with `impl_token! { unsafe MyToken }`.
+///
+/// The token converts into [`Generic`](generic::Generic), so the generic vectors can be used
+/// through [`ShimToken`](crate::shim::ShimToken) for any scalars and widths without a custom
+/// vector type.
+///
+/// # Safety
+/// The invocation must be marked `unsafe`, because it implements the unsafe [`Token`] trait.
+/// The type must not be constructible other than through [`Token`], and the listed features must
+/// include every CPU feature required by the vectors using the token.
+///
+/// ```
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// # {
+/// use generic_simd::{arch::Token, impl_token};
+///
+/// /// AVX2 instruction set token.
+/// #[derive(Copy, Clone, Debug)]
+/// pub struct Avx2(());
+///
+/// impl_token! { unsafe Avx2 => "avx2" }
+///
+/// if let Some(token) = Avx2::new() {
+///     println!("{:?} is supported", token);
+/// }
+/// # }
+/// ```
+///
+/// Omitting `unsafe` is an error:
+/// ```compile_fail
+/// use generic_simd::impl_token;
+///
+/// #[derive(Copy, Clone, Debug)]
+/// pub struct MyToken(());
+///
+/// impl_token! { MyToken }
+/// ```
+#[macro_export]
 macro_rules! impl_token {
-    { $name:ident => $($features:tt),+ } => {
+    { unsafe $name:ident } => {
+        $crate::impl_token! { @impl $name, true, all() }
+    };
+    { unsafe $name:ident => $($features:tt),+ } => {
+        $crate::impl_token! {
+            @impl $name,
+            $crate::are_cpu_features_detected!($($features),*),
+            all($(target_feature = $features),*)
+        }
+    };
+    { @impl $name:ident, $detected:expr, $enabled:meta } => {
         unsafe impl $crate::arch::Token for $name {
             #[inline]
             fn new() -> Option<Self> {
-                if multiversion::are_cpu_features_detected!($($features),*) {
+                if $detected {
                     Some(Self(()))
                 } else {
                     None
@@ -36,17 +113,61 @@ macro_rules! impl_token {
             }
         }
 
+        #[cfg($enabled)]
+        impl $name {
+            /// Returns the token, which is supported because the required CPU features are
+            /// enabled at compile time.
+            #[inline]
+            pub fn get() -> Self {
+                unsafe { <Self as $crate::arch::Token>::new_unchecked() }
+            }
+        }
+
         impl core::convert::From<$name> for $crate::arch::generic::Generic {
             #[inline]
             fn from(_: $name) -> Self {
                 Self
             }
         }
-    }
+    };
 }
 
+// Implements a token that is detected by `AnyToken`.
+#[allow(unused_macros)]
+macro_rules! arch_token {
+    { $name:ident => $($features:tt),+ } => {
+        crate::impl_token! { unsafe $name => $($features),+ }
+
+        impl $name {
+            // Calls the function from a wrapper compiled with the token's features enabled.
+            #[allow(dead_code)]
+            #[target_feature($(enable = $features),*)]
+            #[inline]
+            pub(crate) unsafe fn dispatch<F: $crate::arch::Dispatch<Self>>(self, f: F) -> F::Output {
+                f.call(self)
+            }
+        }
+
+        impl core::convert::From<$name> for $crate::arch::AnyToken {
+            #[inline]
+            fn from(token: $name) -> Self {
+                Self::$name(token)
+            }
+        }
+    };
+}
+
+mod any;
+pub use any::*;
+
+mod composite;
+
+pub mod emulated;
 pub mod generic;
 
+#[cfg(feature = "nightly")]
+pub mod portable;
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub mod x86;
 
@@ -60,6 +181,128 @@ pub mod arm;
 ))]
 pub mod wasm;
 
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2",
+    target_feature = "bmi1",
+    target_feature = "bmi2",
+    target_feature = "f16c",
+    target_feature = "fma",
+    target_feature = "lzcnt",
+    target_feature = "popcnt",
+))]
+pub type StaticToken = x86::X86V3;
+
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx",
+    not(all(
+        target_feature = "avx2",
+        target_feature = "bmi1",
+        target_feature = "bmi2",
+        target_feature = "f16c",
+        target_feature = "fma",
+        target_feature = "lzcnt",
+        target_feature = "popcnt"
+    )),
+))]
+pub type StaticToken = x86::Avx;
+
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse3",
+    target_feature = "ssse3",
+    target_feature = "sse4.1",
+    target_feature = "sse4.2",
+    target_feature = "popcnt",
+    not(target_feature = "avx"),
+))]
+pub type StaticToken = x86::X86V2;
+
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse4.1",
+    not(target_feature = "avx"),
+    not(all(
+        target_feature = "sse3",
+        target_feature = "ssse3",
+        target_feature = "sse4.1",
+        target_feature = "sse4.2",
+        target_feature = "popcnt"
+    )),
+))]
+pub type StaticToken = x86::Sse;
+
+/// The best token supported by the CPU features enabled at compile time.
+///
+/// When building with target features enabled, such as with `-C target-cpu=native`, this token
+/// can be obtained with `StaticToken::get()` without any runtime detection.
+/// The limit set by [`set_max_token`] doesn't apply to this token.
+///
+/// ```
+/// use generic_simd::{arch::StaticToken, scalar::ScalarExt};
+///
+/// let x = 1f32.splat_native(StaticToken::get());
+/// assert!(x.iter().all(|x| *x == 1.));
+/// ```
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2",
+    not(target_feature = "sse4.1"),
+))]
+pub type StaticToken = x86::Sse2;
+
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(feature = "nightly", target_arch = "aarch64", target_feature = "neon"))]
+pub type StaticToken = arm::Neon;
+
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "nightly",
+))]
+pub type StaticToken = wasm::Simd128;
+
+/// The best token supported by the CPU features enabled at compile time.
+#[cfg(all(
+    feature = "nightly",
+    not(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128"),
+    )),
+))]
+pub type StaticToken = portable::Portable;
+
+/// The best token supported by the CPU features enabled at compile time.
+///
+/// When building with target features enabled, such as with `-C target-cpu=native`, this token
+/// can be obtained with `StaticToken::get()` without any runtime detection.
+/// The limit set by [`set_max_token`] doesn't apply to this token.
+///
+/// ```
+/// use generic_simd::{arch::StaticToken, scalar::ScalarExt};
+///
+/// let x = 1f32.splat_native(StaticToken::get());
+/// assert!(x.iter().all(|x| *x == 1.));
+/// ```
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    feature = "nightly",
+)))]
+pub type StaticToken = generic::Generic;
+
 /// Invokes a macro with the supported token types.
 ///
 /// Invokes the macro with the list of [`Token`] types as arguments in priority order, delimited
@@ -86,16 +329,7 @@ macro_rules! call_macro_with_tokens {
     { $mac:ident } => { $crate::call_macro_with_tokens_impl! { $mac } }
 }
 
-#[cfg(not(any(
-    target_arch = "x86",
-    target_arch = "x86_64",
-    all(target_arch = "aarch64", feature = "nightly"),
-    all(
-        target_arch = "wasm32",
-        target_feature = "simd128",
-        feature = "nightly",
-    ),
-)))]
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", feature = "nightly")))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! call_macro_with_tokens_impl {
@@ -106,14 +340,57 @@ macro_rules! call_macro_with_tokens_impl {
     }
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    feature = "nightly",
+    not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        all(target_arch = "wasm32", target_feature = "simd128"),
+    )),
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! call_macro_with_tokens_impl {
+    { $mac:ident } => {
+        $mac! {
+            $crate::arch::portable::Portable,
+            $crate::arch::generic::Generic,
+        }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "nightly"),
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! call_macro_with_tokens_impl {
+    { $mac:ident } => {
+        $mac! {
+            $crate::arch::x86::X86V3,
+            $crate::arch::x86::Avx,
+            $crate::arch::x86::X86V2,
+            $crate::arch::x86::Sse,
+            $crate::arch::x86::Sse2,
+            $crate::arch::generic::Generic,
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! call_macro_with_tokens_impl {
     { $mac:ident } => {
         $mac! {
+            $crate::arch::x86::X86V3,
             $crate::arch::x86::Avx,
+            $crate::arch::x86::X86V2,
             $crate::arch::x86::Sse,
+            $crate::arch::x86::Sse2,
+            $crate::arch::portable::Portable,
             $crate::arch::generic::Generic,
         }
     }
@@ -126,6 +403,7 @@ macro_rules! call_macro_with_tokens_impl {
     { $mac:ident } => {
         $mac! {
             $crate::arch::arm::Neon,
+            $crate::arch::portable::Portable,
             $crate::arch::generic::Generic,
         }
     }
@@ -142,6 +420,7 @@ macro_rules! call_macro_with_tokens_impl {
     { $mac:ident } => {
         $mac! {
             $crate::arch::wasm::Simd128,
+            $crate::arch::portable::Portable,
             $crate::arch::generic::Generic,
         }
     }
@@ -0,0 +1,327 @@
+//! Portable vector types, implemented with `core::simd`.
+//!
+//! Requires feature `"nightly"`.
+//!
+//! The vectors are 128 bits wide, and the compiler selects the instructions for the target.
+//! This provides vectorized code for architectures without a handwritten implementation, and a
+//! reference for comparing the code generated by the handwritten implementations.
+
+use crate::{
+    arch::{any::portable_allowed, generic, Token},
+    shim::{Shim, ShimToken},
+    vector::{width, Classify, Horizontal, PrefixSum, Vector},
+};
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+use core::simd::{num::SimdFloat, Simd};
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+/// Portable SIMD token, implemented with `core::simd`.
+///
+/// Requires feature `"nightly"`.
+///
+/// This token is always supported, unless limited by [`set_max_token`](super::set_max_token).
+#[derive(Copy, Clone, Debug)]
+pub struct Portable(());
+
+impl Portable {
+    /// Returns the token, which is always supported.
+    #[inline]
+    pub fn get() -> Self {
+        Self(())
+    }
+}
+
+unsafe impl Token for Portable {
+    #[inline]
+    fn new() -> Option<Self> {
+        if portable_allowed() {
+            Some(Self(()))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    unsafe fn new_unchecked() -> Self {
+        Self(())
+    }
+}
+
+impl From<Portable> for generic::Generic {
+    #[inline]
+    fn from(_: Portable) -> Self {
+        Self
+    }
+}
+
+/// A portable vector of `f32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f32x4(Simd<f32, 4>);
+
+/// A portable vector of `f64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f64x2(Simd<f64, 2>);
+
+/// A portable vector of `i32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x4(Simd<i32, 4>);
+
+/// A portable vector of `u32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x4(Simd<u32, 4>);
+
+/// A portable vector of `u64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x2(Simd<u64, 2>);
+
+macro_rules! implement {
+    {
+        $vector:ident, $scalar:ty, $generic:ty, 4
+    } => {
+        impl_scalar! {
+            Portable, $scalar, native 4 {
+                1 => ShimToken<$generic, Self, Portable>,
+                2 => ShimToken<Shim<$generic, Self, 2>, Self, Portable>,
+            }
+            4.. => $vector
+        }
+
+        implement! { @vector $vector, $scalar, W4, 4 }
+    };
+    {
+        $vector:ident, $scalar:ty, $generic:ty, 2
+    } => {
+        impl_scalar! {
+            Portable, $scalar, native 2 {
+                1 => ShimToken<$generic, Self, Portable>,
+            }
+            2.. => $vector
+        }
+
+        implement! { @vector $vector, $scalar, W2, 2 }
+    };
+    {
+        @vector $vector:ident, $scalar:ty, $width:ident, $lanes:literal
+    } => {
+        // `Simd` operators wrap on integer overflow, like the other instruction sets.  This
+        // includes dividing the minimum signed integer by -1, which doesn't panic.
+        arithmetic_ops! {
+            for $vector:
+                add -> (core::ops::Add::add),
+                sub -> (core::ops::Sub::sub),
+                mul -> (core::ops::Mul::mul),
+                div -> (core::ops::Div::div)
+        }
+
+        as_slice! { $vector }
+
+        impl PrefixSum for $vector {}
+
+        impl Horizontal for $vector {}
+
+        unsafe impl Vector for $vector {
+            type Scalar = $scalar;
+
+            type Token = Portable;
+
+            type Width = width::$width;
+
+            type Underlying = Simd<$scalar, $lanes>;
+
+            #[inline]
+            fn zeroed(_: Self::Token) -> Self {
+                Self(Simd::splat(<$scalar>::default()))
+            }
+
+            #[inline]
+            fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+                Self(Simd::splat(from))
+            }
+        }
+    };
+}
+
+implement! { f32x4, f32, generic::f32x1, 4 }
+implement! { f64x2, f64, generic::f64x1, 2 }
+implement! { i32x4, i32, generic::i32x1, 4 }
+implement! { u32x4, u32, generic::u32x1, 4 }
+implement! { u64x2, u64, generic::u64x1, 2 }
+
+macro_rules! shim_scalar {
+    { $scalar:ty, $generic:ty } => {
+        impl_scalar! {
+            Portable, $scalar, native 1 {}
+            1.. => ShimToken<$generic, Self, Portable>
+        }
+    }
+}
+
+#[cfg(feature = "complex")]
+shim_scalar! { Complex<f32>, generic::cf32x1 }
+#[cfg(feature = "complex")]
+shim_scalar! { Complex<f64>, generic::cf64x1 }
+
+// Floating-point `Simd` has no bitwise operators, so operate on the bits.
+trait Bits: SimdFloat<Bits = <Self as Bits>::Bits> {
+    type Bits: BitAnd<Output = <Self as Bits>::Bits>
+        + BitOr<Output = <Self as Bits>::Bits>
+        + BitXor<Output = <Self as Bits>::Bits>
+        + Not<Output = <Self as Bits>::Bits>;
+}
+
+impl Bits for Simd<f32, 4> {
+    type Bits = Simd<u32, 4>;
+}
+
+impl Bits for Simd<f64, 2> {
+    type Bits = Simd<u64, 2>;
+}
+
+#[inline]
+fn and<V: Bits>(a: V, b: V) -> V {
+    V::from_bits(a.to_bits() & b.to_bits())
+}
+
+#[inline]
+fn or<V: Bits>(a: V, b: V) -> V {
+    V::from_bits(a.to_bits() | b.to_bits())
+}
+
+#[inline]
+fn xor<V: Bits>(a: V, b: V) -> V {
+    V::from_bits(a.to_bits() ^ b.to_bits())
+}
+
+#[inline]
+fn not<V: Bits>(a: V) -> V {
+    V::from_bits(!a.to_bits())
+}
+
+#[inline]
+fn and_not<V: Bits>(a: V, b: V) -> V {
+    V::from_bits(a.to_bits() & !b.to_bits())
+}
+
+bitwise_ops! {
+    for f32x4:
+        and -> (and),
+        or -> (or),
+        xor -> (xor),
+        not -> (not),
+        and_not -> (and_not)
+}
+
+bitwise_ops! {
+    for f64x2:
+        and -> (and),
+        or -> (or),
+        xor -> (xor),
+        not -> (not),
+        and_not -> (and_not)
+}
+
+// `Simd` shifts wrap the shift amount, so saturate like the other instruction sets.
+trait SaturatingShift {
+    fn shl(self, rhs: u32) -> Self;
+    fn shr(self, rhs: u32) -> Self;
+}
+
+macro_rules! implement_int {
+    {
+        $vector:ident, $scalar:ty, $lanes:literal
+    } => {
+        impl SaturatingShift for Simd<$scalar, $lanes> {
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                if rhs < <$scalar>::BITS {
+                    self << Simd::splat(rhs as $scalar)
+                } else {
+                    Simd::splat(0)
+                }
+            }
+
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                if rhs < <$scalar>::BITS {
+                    self >> Simd::splat(rhs as $scalar)
+                } else {
+                    // Fills with the sign bit of signed integers and with zero for unsigned
+                    // integers.
+                    (self >> Simd::splat((<$scalar>::BITS - 1) as $scalar)) >> Simd::splat(1)
+                }
+            }
+        }
+
+        bitwise_ops! {
+            for $vector:
+                and -> (BitAnd::bitand),
+                or -> (BitOr::bitor),
+                xor -> (BitXor::bitxor),
+                not -> (Not::not),
+                and_not -> ()
+        }
+
+        shift_ops! {
+            for $vector:
+                shl -> (SaturatingShift::shl),
+                shr -> (SaturatingShift::shr)
+        }
+    }
+}
+
+implement_int! { i32x4, i32, 4 }
+implement_int! { u32x4, u32, 4 }
+implement_int! { u64x2, u64, 2 }
+
+impl Classify for f32x4 {}
+impl Classify for f64x2 {}
+
+impl core::ops::Neg for f32x4 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl core::ops::Neg for f64x2 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl core::ops::Neg for i32x4 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+convert_lanes! { ShimToken<Shim<generic::f32x1, f32, 2>, f32, Portable> => f64x2 }
+convert_lanes! { f64x2 => ShimToken<Shim<generic::f32x1, f32, 2>, f32, Portable> }
+convert_lanes! { f64x2 => int ShimToken<Shim<generic::i32x1, i32, 2>, i32, Portable> }
+convert_lanes! { ShimToken<Shim<generic::i32x1, i32, 2>, i32, Portable> => f64x2 }
+convert_lanes! { f32x4 => Shim<f64x2, f64, 2> }
+convert_lanes! { f32x4 => int i32x4 }
+convert_lanes! { Shim<f64x2, f64, 2> => f32x4 }
+convert_lanes! { Shim<f64x2, f64, 2> => int i32x4 }
+convert_lanes! { i32x4 => f32x4 }
+convert_lanes! { i32x4 => Shim<f64x2, f64, 2> }
@@ -1,21 +1,12 @@
 use core::arch::wasm32::*;
 
 use crate::{
-    arch::{generic, wasm::*, Token},
-    scalar::Scalar,
-    shim::{Shim2, Shim4, Shim8, ShimToken},
-    vector::{width, Native, Vector},
+    arch::{generic, wasm::*},
+    shim::ShimToken,
+    vector::{width, Vector},
 };
 use num_complex::Complex;
 
-impl Native<Simd128> for Complex<f32> {
-    type Width = width::W2;
-}
-
-impl Native<Simd128> for Complex<f64> {
-    type Width = width::W1;
-}
-
 /// A simd128 vector of `Complex<f32>`s.
 ///
 /// Requires feature `"complex"`.
@@ -32,41 +23,27 @@ pub struct cf32x2(v128);
 #[allow(non_camel_case_types)]
 pub struct cf64x1(v128);
 
-impl Scalar<Simd128, width::W1> for Complex<f32> {
-    type Vector = ShimToken<generic::cf32x1, Self, Simd128>;
-}
-
-impl Scalar<Simd128, width::W2> for Complex<f32> {
-    type Vector = cf32x2;
-}
-
-impl Scalar<Simd128, width::W4> for Complex<f32> {
-    type Vector = Shim2<cf32x2, Complex<f32>>;
-}
-
-impl Scalar<Simd128, width::W8> for Complex<f32> {
-    type Vector = Shim4<cf32x2, Complex<f32>>;
-}
-
-impl Scalar<Simd128, width::W1> for Complex<f64> {
-    type Vector = cf64x1;
-}
-
-impl Scalar<Simd128, width::W2> for Complex<f64> {
-    type Vector = Shim2<cf64x1, Complex<f64>>;
-}
-
-impl Scalar<Simd128, width::W4> for Complex<f64> {
-    type Vector = Shim4<cf64x1, Complex<f64>>;
+impl_scalar! {
+    Simd128, Complex<f32>, native 2 {
+        1 => ShimToken<generic::cf32x1, Self, Simd128>,
+    }
+    2.. => cf32x2
 }
 
-impl Scalar<Simd128, width::W8> for Complex<f64> {
-    type Vector = Shim8<cf64x1, Complex<f64>>;
+impl_scalar! {
+    Simd128, Complex<f64>, native 1 {}
+    1.. => cf64x1
 }
 
 as_slice! { cf32x2 }
 as_slice! { cf64x1 }
 
+impl crate::vector::PrefixSum for cf32x2 {}
+impl crate::vector::PrefixSum for cf64x1 {}
+
+impl crate::vector::Horizontal for cf32x2 {}
+impl crate::vector::Horizontal for cf64x1 {}
+
 unsafe impl Vector for cf32x2 {
     type Scalar = Complex<f32>;
     type Token = Simd128;
@@ -102,21 +79,19 @@ unsafe impl Vector for cf64x1 {
 }
 
 arithmetic_ops! {
-    feature: Simd128::new_unchecked(),
     for cf32x2:
-        add -> (f32x4_add),
-        sub -> (f32x4_sub),
-        mul -> (cf32x2_mul),
-        div -> (cf32x2_div)
+        add -> (unsafe f32x4_add),
+        sub -> (unsafe f32x4_sub),
+        mul -> (unsafe cf32x2_mul),
+        div -> (unsafe cf32x2_div)
 }
 
 arithmetic_ops! {
-    feature: Simd128::new_unchecked(),
     for cf64x1:
-        add -> (f64x2_add),
-        sub -> (f64x2_sub),
-        mul -> (cf64x1_mul),
-        div -> (cf64x1_div)
+        add -> (unsafe f64x2_add),
+        sub -> (unsafe f64x2_sub),
+        mul -> (unsafe cf64x1_mul),
+        div -> (unsafe cf64x1_div)
 }
 
 #[target_feature(enable = "simd128")]
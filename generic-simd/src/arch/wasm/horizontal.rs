@@ -0,0 +1,70 @@
+use core::arch::wasm32::*;
+
+use crate::{arch::wasm::*, vector::Horizontal};
+
+macro_rules! implement {
+    {
+        $vector:ty, pairs -> $pairs:path, sum -> $sum:path, dot -> $dot:path
+    } => {
+        impl Horizontal for $vector {
+            #[inline]
+            fn horizontal_add_pairs(self, rhs: Self) -> Self {
+                Self(unsafe { $pairs(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn horizontal_sum(self) -> Self::Scalar {
+                unsafe { $sum(self.0) }
+            }
+
+            #[inline]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                unsafe { $dot(self.0, rhs.0) }
+            }
+        }
+    }
+}
+
+// Pairs are gathered into even and odd lanes by shuffling, then added.
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn add_pairs_f32x4(a: v128, b: v128) -> v128 {
+    f32x4_add(
+        v32x4_shuffle::<0, 2, 4, 6>(a, b),
+        v32x4_shuffle::<1, 3, 5, 7>(a, b),
+    )
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn sum_f32x4(x: v128) -> f32 {
+    let x = f32x4_add(x, v32x4_shuffle::<2, 3, 0, 1>(x, x));
+    f32x4_extract_lane::<0>(f32x4_add(x, v32x4_shuffle::<1, 0, 3, 2>(x, x)))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn dot_f32x4(a: v128, b: v128) -> f32 {
+    sum_f32x4(f32x4_mul(a, b))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn add_pairs_f64x2(a: v128, b: v128) -> v128 {
+    f64x2_add(v64x2_shuffle::<0, 2>(a, b), v64x2_shuffle::<1, 3>(a, b))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn sum_f64x2(x: v128) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_add(x, v64x2_shuffle::<1, 0>(x, x)))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn dot_f64x2(a: v128, b: v128) -> f64 {
+    sum_f64x2(f64x2_mul(a, b))
+}
+
+implement! { f32x4, pairs -> add_pairs_f32x4, sum -> sum_f32x4, dot -> dot_f32x4 }
+implement! { f64x2, pairs -> add_pairs_f64x2, sum -> sum_f64x2, dot -> dot_f64x2 }
@@ -5,11 +5,13 @@ mod complex;
 #[cfg(feature = "complex")]
 pub use complex::*;
 
+mod horizontal;
+mod scan;
+
 use crate::{
-    arch::{generic, Token},
-    scalar::Scalar,
-    shim::{Shim2, Shim4, ShimToken},
-    vector::{width, Native, Vector},
+    arch::generic,
+    shim::{Shim, ShimToken},
+    vector::{width, Classify, Vector},
 };
 use core::arch::wasm32::*;
 
@@ -17,15 +19,7 @@ use core::arch::wasm32::*;
 #[derive(Copy, Clone, Debug)]
 pub struct Simd128(());
 
-impl_token! { Simd128 => "simd128" }
-
-impl Native<Simd128> for f32 {
-    type Width = width::W4;
-}
-
-impl Native<Simd128> for f64 {
-    type Width = width::W2;
-}
+arch_token! { Simd128 => "simd128" }
 
 /// A simd128 vector of `f32`s.
 #[derive(Clone, Copy, Debug)]
@@ -39,37 +33,46 @@ pub struct f32x4(v128);
 #[allow(non_camel_case_types)]
 pub struct f64x2(v128);
 
-impl Scalar<Simd128, width::W1> for f32 {
-    type Vector = ShimToken<generic::f32x1, Self, Simd128>;
-}
-
-impl Scalar<Simd128, width::W2> for f32 {
-    type Vector = ShimToken<Shim2<generic::f32x1, Self>, Self, Simd128>;
-}
-
-impl Scalar<Simd128, width::W4> for f32 {
-    type Vector = f32x4;
+impl_scalar! {
+    Simd128, f32, native 4 {
+        1 => ShimToken<generic::f32x1, Self, Simd128>,
+        2 => ShimToken<Shim<generic::f32x1, Self, 2>, Self, Simd128>,
+    }
+    4.. => f32x4
 }
 
-impl Scalar<Simd128, width::W8> for f32 {
-    type Vector = Shim2<f32x4, f32>;
+impl_scalar! {
+    Simd128, f64, native 2 {
+        1 => ShimToken<generic::f64x1, Self, Simd128>,
+    }
+    2.. => f64x2
 }
 
-impl Scalar<Simd128, width::W1> for f64 {
-    type Vector = ShimToken<generic::f64x1, Self, Simd128>;
+impl_scalar! {
+    Simd128, i32, native 4 {}
+    1.. => ShimToken<generic::i32x1, Self, Simd128>
 }
 
-impl Scalar<Simd128, width::W2> for f64 {
-    type Vector = f64x2;
+impl_scalar! {
+    Simd128, u32, native 4 {}
+    1.. => ShimToken<generic::u32x1, Self, Simd128>
 }
 
-impl Scalar<Simd128, width::W4> for f64 {
-    type Vector = Shim2<f64x2, f64>;
+impl_scalar! {
+    Simd128, u64, native 2 {}
+    1.. => ShimToken<generic::u64x1, Self, Simd128>
 }
 
-impl Scalar<Simd128, width::W8> for f64 {
-    type Vector = Shim4<f64x2, f64>;
-}
+convert_lanes! { ShimToken<Shim<generic::f32x1, f32, 2>, f32, Simd128> => f64x2 }
+convert_lanes! { ShimToken<Shim<generic::f32x1, f32, 2>, f32, Simd128> => int Shim<ShimToken<generic::i32x1, i32, Simd128>, i32, 2> }
+convert_lanes! { f64x2 => ShimToken<Shim<generic::f32x1, f32, 2>, f32, Simd128> }
+convert_lanes! { f64x2 => int Shim<ShimToken<generic::i32x1, i32, Simd128>, i32, 2> }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Simd128>, i32, 2> => ShimToken<Shim<generic::f32x1, f32, 2>, f32, Simd128> }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Simd128>, i32, 2> => f64x2 }
+convert_lanes! { f32x4 => Shim<f64x2, f64, 2> }
+convert_lanes! { f32x4 => int Shim<ShimToken<generic::i32x1, i32, Simd128>, i32, 4> }
+convert_lanes! { Shim<f64x2, f64, 2> => f32x4 }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Simd128>, i32, 4> => f32x4 }
 
 as_slice! { f32x4 }
 as_slice! { f64x2 }
@@ -109,23 +112,42 @@ unsafe impl Vector for f64x2 {
 }
 
 arithmetic_ops! {
-    feature: Simd128::new_unchecked(),
     for f32x4:
-        add -> (f32x4_add),
-        sub -> (f32x4_sub),
-        mul -> (f32x4_mul),
-        div -> (f32x4_div)
+        add -> (unsafe f32x4_add),
+        sub -> (unsafe f32x4_sub),
+        mul -> (unsafe f32x4_mul),
+        div -> (unsafe f32x4_div)
 }
 
 arithmetic_ops! {
-    feature: Simd128::new_unchecked(),
     for f64x2:
-        add -> (f64x2_add),
-        sub -> (f64x2_sub),
-        mul -> (f64x2_mul),
-        div -> (f64x2_div)
+        add -> (unsafe f64x2_add),
+        sub -> (unsafe f64x2_sub),
+        mul -> (unsafe f64x2_mul),
+        div -> (unsafe f64x2_div)
+}
+
+bitwise_ops! {
+    for f32x4:
+        and -> (unsafe v128_and),
+        or -> (unsafe v128_or),
+        xor -> (unsafe v128_xor),
+        not -> (unsafe v128_not),
+        and_not -> ()
+}
+
+bitwise_ops! {
+    for f64x2:
+        and -> (unsafe v128_and),
+        or -> (unsafe v128_or),
+        xor -> (unsafe v128_xor),
+        not -> (unsafe v128_not),
+        and_not -> ()
 }
 
+impl Classify for f32x4 {}
+impl Classify for f64x2 {}
+
 impl core::ops::Neg for f32x4 {
     type Output = Self;
 
@@ -0,0 +1,51 @@
+use core::arch::wasm32::*;
+
+use crate::{arch::wasm::*, vector::PrefixSum};
+
+macro_rules! implement {
+    {
+        $vector:ty, $inclusive:ident, $exclusive:ident
+    } => {
+        impl PrefixSum for $vector {
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                Self(unsafe { $inclusive(self.0) })
+            }
+
+            #[inline]
+            fn exclusive_prefix_sum(self) -> Self {
+                Self(unsafe { $inclusive($exclusive(self.0)) })
+            }
+        }
+    }
+}
+
+// Shuffling with a zero vector shifts lanes up, shifting in zeros.
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn prefix_sum_f32x4(x: v128) -> v128 {
+    let zero = f32x4_splat(0.);
+    let x = f32x4_add(x, v32x4_shuffle::<4, 0, 1, 2>(x, zero));
+    f32x4_add(x, v32x4_shuffle::<4, 5, 0, 1>(x, zero))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn shift_lane_f32x4(x: v128) -> v128 {
+    v32x4_shuffle::<4, 0, 1, 2>(x, f32x4_splat(0.))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn prefix_sum_f64x2(x: v128) -> v128 {
+    f64x2_add(x, shift_lane_f64x2(x))
+}
+
+#[target_feature(enable = "simd128")]
+#[inline]
+unsafe fn shift_lane_f64x2(x: v128) -> v128 {
+    v64x2_shuffle::<2, 0>(x, f64x2_splat(0.))
+}
+
+implement! { f32x4, prefix_sum_f32x4, shift_lane_f32x4 }
+implement! { f64x2, prefix_sum_f64x2, shift_lane_f64x2 }
@@ -0,0 +1,233 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    arch::x86::*,
+    vector::{lane_mask_from_fn, Classify, LaneMask, Vector},
+};
+
+macro_rules! implement {
+    {
+        $vector:ty, $nan:ident, $infinite:ident, $finite:ident, $subnormal:ident, $sign:ident
+    } => {
+        impl Classify for $vector {
+            #[inline]
+            fn is_nan(self) -> LaneMask<Self> {
+                to_mask::<Self>(unsafe { $nan(self.0) })
+            }
+
+            #[inline]
+            fn is_infinite(self) -> LaneMask<Self> {
+                to_mask::<Self>(unsafe { $infinite(self.0) })
+            }
+
+            #[inline]
+            fn is_finite(self) -> LaneMask<Self> {
+                to_mask::<Self>(unsafe { $finite(self.0) })
+            }
+
+            #[inline]
+            fn is_subnormal(self) -> LaneMask<Self> {
+                to_mask::<Self>(unsafe { $subnormal(self.0) })
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> LaneMask<Self> {
+                to_mask::<Self>(unsafe { $sign(self.0) })
+            }
+        }
+    }
+}
+
+// Expands the bits produced by `movemask` to one `bool` per lane.
+#[inline]
+fn to_mask<V>(bits: i32) -> LaneMask<V>
+where
+    V: Vector,
+    V::Width: crate::vector::width::ArrayOf<bool>,
+{
+    lane_mask_from_fn::<V, _>(|i| bits & (1 << i) != 0)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn abs_ps(x: __m128) -> __m128 {
+    _mm_andnot_ps(_mm_set1_ps(-0.), x)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn nan_ps(x: __m128) -> i32 {
+    _mm_movemask_ps(_mm_cmpunord_ps(x, x))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn infinite_ps(x: __m128) -> i32 {
+    _mm_movemask_ps(_mm_cmpeq_ps(abs_ps(x), _mm_set1_ps(f32::INFINITY)))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn finite_ps(x: __m128) -> i32 {
+    _mm_movemask_ps(_mm_cmplt_ps(abs_ps(x), _mm_set1_ps(f32::INFINITY)))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn subnormal_ps(x: __m128) -> i32 {
+    let abs = abs_ps(x);
+    _mm_movemask_ps(_mm_and_ps(
+        _mm_cmplt_ps(abs, _mm_set1_ps(f32::MIN_POSITIVE)),
+        _mm_cmpneq_ps(abs, _mm_setzero_ps()),
+    ))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn sign_ps(x: __m128) -> i32 {
+    _mm_movemask_ps(x)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn abs_pd(x: __m128d) -> __m128d {
+    _mm_andnot_pd(_mm_set1_pd(-0.), x)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn nan_pd(x: __m128d) -> i32 {
+    _mm_movemask_pd(_mm_cmpunord_pd(x, x))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn infinite_pd(x: __m128d) -> i32 {
+    _mm_movemask_pd(_mm_cmpeq_pd(abs_pd(x), _mm_set1_pd(f64::INFINITY)))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn finite_pd(x: __m128d) -> i32 {
+    _mm_movemask_pd(_mm_cmplt_pd(abs_pd(x), _mm_set1_pd(f64::INFINITY)))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn subnormal_pd(x: __m128d) -> i32 {
+    let abs = abs_pd(x);
+    _mm_movemask_pd(_mm_and_pd(
+        _mm_cmplt_pd(abs, _mm_set1_pd(f64::MIN_POSITIVE)),
+        _mm_cmpneq_pd(abs, _mm_setzero_pd()),
+    ))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn sign_pd(x: __m128d) -> i32 {
+    _mm_movemask_pd(x)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn abs_ps256(x: __m256) -> __m256 {
+    _mm256_andnot_ps(_mm256_set1_ps(-0.), x)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn nan_ps256(x: __m256) -> i32 {
+    _mm256_movemask_ps(_mm256_cmp_ps(x, x, _CMP_UNORD_Q))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn infinite_ps256(x: __m256) -> i32 {
+    _mm256_movemask_ps(_mm256_cmp_ps(
+        abs_ps256(x),
+        _mm256_set1_ps(f32::INFINITY),
+        _CMP_EQ_OQ,
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn finite_ps256(x: __m256) -> i32 {
+    _mm256_movemask_ps(_mm256_cmp_ps(
+        abs_ps256(x),
+        _mm256_set1_ps(f32::INFINITY),
+        _CMP_LT_OQ,
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn subnormal_ps256(x: __m256) -> i32 {
+    let abs = abs_ps256(x);
+    _mm256_movemask_ps(_mm256_and_ps(
+        _mm256_cmp_ps(abs, _mm256_set1_ps(f32::MIN_POSITIVE), _CMP_LT_OQ),
+        _mm256_cmp_ps(abs, _mm256_setzero_ps(), _CMP_NEQ_OQ),
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn sign_ps256(x: __m256) -> i32 {
+    _mm256_movemask_ps(x)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn abs_pd256(x: __m256d) -> __m256d {
+    _mm256_andnot_pd(_mm256_set1_pd(-0.), x)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn nan_pd256(x: __m256d) -> i32 {
+    _mm256_movemask_pd(_mm256_cmp_pd(x, x, _CMP_UNORD_Q))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn infinite_pd256(x: __m256d) -> i32 {
+    _mm256_movemask_pd(_mm256_cmp_pd(
+        abs_pd256(x),
+        _mm256_set1_pd(f64::INFINITY),
+        _CMP_EQ_OQ,
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn finite_pd256(x: __m256d) -> i32 {
+    _mm256_movemask_pd(_mm256_cmp_pd(
+        abs_pd256(x),
+        _mm256_set1_pd(f64::INFINITY),
+        _CMP_LT_OQ,
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn subnormal_pd256(x: __m256d) -> i32 {
+    let abs = abs_pd256(x);
+    _mm256_movemask_pd(_mm256_and_pd(
+        _mm256_cmp_pd(abs, _mm256_set1_pd(f64::MIN_POSITIVE), _CMP_LT_OQ),
+        _mm256_cmp_pd(abs, _mm256_setzero_pd(), _CMP_NEQ_OQ),
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn sign_pd256(x: __m256d) -> i32 {
+    _mm256_movemask_pd(x)
+}
+
+implement! { f32x4, nan_ps, infinite_ps, finite_ps, subnormal_ps, sign_ps }
+implement! { f64x2, nan_pd, infinite_pd, finite_pd, subnormal_pd, sign_pd }
+implement! { f32x8, nan_ps256, infinite_ps256, finite_ps256, subnormal_ps256, sign_ps256 }
+implement! { f64x4, nan_pd256, infinite_pd256, finite_pd256, subnormal_pd256, sign_pd256 }
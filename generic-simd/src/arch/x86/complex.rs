@@ -4,29 +4,12 @@ use core::arch::x86::*;
 use core::arch::x86_64::*;
 
 use crate::{
-    arch::{generic, x86::*, Token},
-    scalar::Scalar,
-    shim::{Shim2, Shim4, Shim8, ShimToken},
-    vector::{width, Native, Vector},
+    arch::{generic, x86::*},
+    shim::ShimToken,
+    vector::Vector,
 };
 use num_complex::Complex;
 
-impl Native<Sse> for Complex<f32> {
-    type Width = width::W2;
-}
-
-impl Native<Sse> for Complex<f64> {
-    type Width = width::W1;
-}
-
-impl Native<Avx> for Complex<f32> {
-    type Width = width::W4;
-}
-
-impl Native<Avx> for Complex<f64> {
-    type Width = width::W2;
-}
-
 /// An SSE vector of `Complex<f32>`s.
 ///
 /// Requires feature `"complex"`.
@@ -59,104 +42,63 @@ pub struct cf32x4(__m256);
 #[allow(non_camel_case_types)]
 pub struct cf64x2(__m256d);
 
-impl Scalar<Sse, width::W1> for Complex<f32> {
-    type Vector = ShimToken<generic::cf32x1, Self, Sse>;
-}
-
-impl Scalar<Sse, width::W2> for Complex<f32> {
-    type Vector = cf32x2;
-}
-
-impl Scalar<Sse, width::W4> for Complex<f32> {
-    type Vector = Shim2<cf32x2, Complex<f32>>;
-}
-
-impl Scalar<Sse, width::W8> for Complex<f32> {
-    type Vector = Shim4<cf32x2, Complex<f32>>;
-}
-
-impl Scalar<Sse, width::W1> for Complex<f64> {
-    type Vector = cf64x1;
-}
-
-impl Scalar<Sse, width::W2> for Complex<f64> {
-    type Vector = Shim2<cf64x1, Complex<f64>>;
-}
-
-impl Scalar<Sse, width::W4> for Complex<f64> {
-    type Vector = Shim4<cf64x1, Complex<f64>>;
-}
-
-impl Scalar<Sse, width::W8> for Complex<f64> {
-    type Vector = Shim8<cf64x1, Self>;
-}
-
-impl Scalar<Avx, width::W1> for Complex<f32> {
-    type Vector = ShimToken<generic::cf32x1, Self, Avx>;
-}
-
-impl Scalar<Avx, width::W2> for Complex<f32> {
-    type Vector = ShimToken<cf32x2, Self, Avx>;
-}
-
-impl Scalar<Avx, width::W4> for Complex<f32> {
-    type Vector = cf32x4;
-}
-
-impl Scalar<Avx, width::W8> for Complex<f32> {
-    type Vector = Shim2<cf32x4, Complex<f32>>;
-}
-
-impl Scalar<Avx, width::W1> for Complex<f64> {
-    type Vector = ShimToken<cf64x1, Self, Avx>;
+impl_scalar! {
+    Sse, Complex<f32>, native 2 {
+        1 => ShimToken<generic::cf32x1, Self, Sse>,
+    }
+    2.. => cf32x2
 }
 
-impl Scalar<Avx, width::W2> for Complex<f64> {
-    type Vector = cf64x2;
+impl_scalar! {
+    Sse, Complex<f64>, native 1 {}
+    1.. => cf64x1
 }
 
-impl Scalar<Avx, width::W4> for Complex<f64> {
-    type Vector = Shim2<cf64x2, Complex<f64>>;
+impl_scalar! {
+    Avx, Complex<f32>, native 4 {
+        1 => ShimToken<generic::cf32x1, Self, Avx>,
+        2 => ShimToken<cf32x2, Self, Avx>,
+    }
+    4.. => cf32x4
 }
 
-impl Scalar<Avx, width::W8> for Complex<f64> {
-    type Vector = Shim4<cf64x2, Complex<f64>>;
+impl_scalar! {
+    Avx, Complex<f64>, native 2 {
+        1 => ShimToken<cf64x1, Self, Avx>,
+    }
+    2.. => cf64x2
 }
 
 arithmetic_ops! {
-    feature: Sse::new_unchecked(),
     for cf32x2:
-        add -> (_mm_add_ps),
-        sub -> (_mm_sub_ps),
-        mul -> (mul_cf32x2),
-        div -> (div_cf32x2)
+        add -> (unsafe _mm_add_ps),
+        sub -> (unsafe _mm_sub_ps),
+        mul -> (unsafe mul_cf32x2),
+        div -> (unsafe div_cf32x2)
 }
 
 arithmetic_ops! {
-    feature: Sse::new_unchecked(),
     for cf64x1:
-        add -> (_mm_add_pd),
-        sub -> (_mm_sub_pd),
-        mul -> (mul_cf64x1),
-        div -> (div_cf64x1)
+        add -> (unsafe _mm_add_pd),
+        sub -> (unsafe _mm_sub_pd),
+        mul -> (unsafe mul_cf64x1),
+        div -> (unsafe div_cf64x1)
 }
 
 arithmetic_ops! {
-    feature: Avx::new_unchecked(),
     for cf32x4:
-        add -> (_mm256_add_ps),
-        sub -> (_mm256_sub_ps),
-        mul -> (mul_cf32x4),
-        div -> (div_cf32x4)
+        add -> (unsafe _mm256_add_ps),
+        sub -> (unsafe _mm256_sub_ps),
+        mul -> (unsafe mul_cf32x4),
+        div -> (unsafe div_cf32x4)
 }
 
 arithmetic_ops! {
-    feature: Avx::new_unchecked(),
     for cf64x2:
-        add -> (_mm256_add_pd),
-        sub -> (_mm256_sub_pd),
-        mul -> (mul_cf64x2),
-        div -> (div_cf64x2)
+        add -> (unsafe _mm256_add_pd),
+        sub -> (unsafe _mm256_sub_pd),
+        mul -> (unsafe mul_cf64x2),
+        div -> (unsafe div_cf64x2)
 }
 
 #[target_feature(enable = "sse3")]
@@ -302,6 +244,16 @@ as_slice! { cf32x4 }
 as_slice! { cf64x1 }
 as_slice! { cf64x2 }
 
+impl crate::vector::PrefixSum for cf32x2 {}
+impl crate::vector::PrefixSum for cf32x4 {}
+impl crate::vector::PrefixSum for cf64x1 {}
+impl crate::vector::PrefixSum for cf64x2 {}
+
+impl crate::vector::Horizontal for cf32x2 {}
+impl crate::vector::Horizontal for cf32x4 {}
+impl crate::vector::Horizontal for cf64x1 {}
+impl crate::vector::Horizontal for cf64x2 {}
+
 unsafe impl Vector for cf32x2 {
     type Scalar = Complex<f32>;
 
@@ -320,6 +272,8 @@ unsafe impl Vector for cf32x2 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm_set_ps(from.im, from.re, from.im, from.re) })
     }
+
+    implement_hints! { _mm_stream_ps }
 }
 
 unsafe impl Vector for cf64x1 {
@@ -340,6 +294,8 @@ unsafe impl Vector for cf64x1 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm_set_pd(from.im, from.re) })
     }
+
+    implement_hints! { _mm_stream_pd }
 }
 
 unsafe impl Vector for cf32x4 {
@@ -364,6 +320,8 @@ unsafe impl Vector for cf32x4 {
             ))
         }
     }
+
+    implement_hints! { _mm256_stream_ps }
 }
 
 unsafe impl Vector for cf64x2 {
@@ -384,6 +342,8 @@ unsafe impl Vector for cf64x2 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm256_setr_pd(from.re, from.im, from.re, from.im) })
     }
+
+    implement_hints! { _mm256_stream_pd }
 }
 
 impl crate::vector::Complex for cf32x2 {
@@ -0,0 +1,216 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    arch::{generic, x86::*},
+    shim::{Shim, ShimToken},
+    vector::{Convert, ConvertToInt, Vector},
+};
+use core::marker::PhantomData;
+
+macro_rules! implement {
+    {
+        $from:ty => $to:ty, |$x:ident| $convert:expr
+    } => {
+        impl Convert<$to> for $from {
+            #[inline]
+            fn convert(self, _: <$to as Vector>::Token) -> $to {
+                let $x = self;
+                unsafe { $convert }
+            }
+        }
+    };
+    {
+        $from:ty => int $to:ty, |$x:ident| $truncate:expr, $round:expr, $saturate:expr
+    } => {
+        impl ConvertToInt<$to> for $from {
+            #[inline]
+            fn convert_truncate(self, _: <$to as Vector>::Token) -> $to {
+                let $x = self;
+                unsafe { $truncate }
+            }
+
+            #[inline]
+            fn convert_round(self, _: <$to as Vector>::Token) -> $to {
+                let $x = self;
+                unsafe { $round }
+            }
+
+            #[inline]
+            fn convert_saturate(self, _: <$to as Vector>::Token) -> $to {
+                let $x = self;
+                unsafe { $saturate }
+            }
+        }
+    };
+}
+
+// Truncation produces `i32::MIN` for lanes that are NaN or out of range, so fix up the lanes that
+// are NaN or too large.
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn saturate_ps(x: __m128) -> __m128i {
+    let truncated = _mm_cvttps_epi32(x);
+    let overflow = _mm_castps_si128(_mm_cmpge_ps(x, _mm_set1_ps(2147483648.)));
+    let ordered = _mm_castps_si128(_mm_cmpord_ps(x, x));
+    _mm_and_si128(_mm_xor_si128(truncated, overflow), ordered)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn saturate_ps256(x: __m256) -> __m256i {
+    let truncated = _mm256_castsi256_ps(_mm256_cvttps_epi32(x));
+    let overflow = _mm256_cmp_ps(x, _mm256_set1_ps(2147483648.), _CMP_GE_OQ);
+    let ordered = _mm256_cmp_ps(x, x, _CMP_ORD_Q);
+    _mm256_castps_si256(_mm256_and_ps(_mm256_xor_ps(truncated, overflow), ordered))
+}
+
+// Every `i32` is representable as an `f64`, so NaN lanes can be zeroed and the remaining lanes
+// clamped before truncation.
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn saturate_pd(x: __m128d) -> __m128i {
+    let x = _mm_and_pd(x, _mm_cmpord_pd(x, x));
+    let x = _mm_max_pd(x, _mm_set1_pd(-2147483648.));
+    _mm_cvttpd_epi32(_mm_min_pd(x, _mm_set1_pd(2147483647.)))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn saturate_pd256(x: __m256d) -> __m128i {
+    let x = _mm256_and_pd(x, _mm256_cmp_pd(x, x, _CMP_ORD_Q));
+    let x = _mm256_max_pd(x, _mm256_set1_pd(-2147483648.));
+    _mm256_cvttpd_epi32(_mm256_min_pd(x, _mm256_set1_pd(2147483647.)))
+}
+
+// Vectors of 2 lanes are only native for `f64`, so convert lane-wise.
+convert_lanes! { Shim<generic::f32x1, f32, 2> => f64x2 }
+convert_lanes! { Shim<generic::i32x1, i32, 2> => f64x2 }
+convert_lanes! { f64x2 => Shim<generic::f32x1, f32, 2> }
+convert_lanes! { f64x2 => int Shim<generic::i32x1, i32, 2> }
+convert_lanes! { ShimToken<Shim<generic::f32x1, f32, 2>, f32, Sse> => f64x2 }
+convert_lanes! { ShimToken<Shim<generic::i32x1, i32, 2>, i32, Sse> => f64x2 }
+convert_lanes! { f64x2 => ShimToken<Shim<generic::f32x1, f32, 2>, f32, Sse> }
+convert_lanes! { f64x2 => int ShimToken<Shim<generic::i32x1, i32, 2>, i32, Sse> }
+
+implement! {
+    f32x4 => Shim<f64x2, f64, 2>, |x| Shim(
+        [
+            f64x2(_mm_cvtps_pd(x.0)),
+            f64x2(_mm_cvtps_pd(_mm_movehl_ps(x.0, x.0))),
+        ],
+        PhantomData,
+    )
+}
+
+implement! {
+    i32x4 => f32x4, |x| f32x4(_mm_cvtepi32_ps(x.0))
+}
+
+implement! {
+    i32x4 => Shim<f64x2, f64, 2>, |x| Shim(
+        [
+            f64x2(_mm_cvtepi32_pd(x.0)),
+            f64x2(_mm_cvtepi32_pd(_mm_shuffle_epi32(x.0, 0xee))),
+        ],
+        PhantomData,
+    )
+}
+
+implement! {
+    Shim<f64x2, f64, 2> => f32x4, |x| f32x4(_mm_movelh_ps(
+        _mm_cvtpd_ps((x.0[0]).0),
+        _mm_cvtpd_ps((x.0[1]).0),
+    ))
+}
+
+implement! {
+    f32x4 => int i32x4, |x|
+        i32x4(_mm_cvttps_epi32(x.0)),
+        i32x4(_mm_cvtps_epi32(x.0)),
+        i32x4(saturate_ps(x.0))
+}
+
+implement! {
+    Shim<f64x2, f64, 2> => int i32x4, |x|
+        i32x4(_mm_unpacklo_epi64(
+            _mm_cvttpd_epi32((x.0[0]).0),
+            _mm_cvttpd_epi32((x.0[1]).0),
+        )),
+        i32x4(_mm_unpacklo_epi64(
+            _mm_cvtpd_epi32((x.0[0]).0),
+            _mm_cvtpd_epi32((x.0[1]).0),
+        )),
+        i32x4(_mm_unpacklo_epi64(
+            saturate_pd((x.0[0]).0),
+            saturate_pd((x.0[1]).0),
+        ))
+}
+
+implement! {
+    ShimToken<f32x4, f32, Avx> => f64x4, |x| f64x4(_mm256_cvtps_pd((x.0).0))
+}
+
+implement! {
+    ShimToken<i32x4, i32, Avx> => f64x4, |x| f64x4(_mm256_cvtepi32_pd((x.0).0))
+}
+
+implement! {
+    f64x4 => ShimToken<f32x4, f32, Avx>, |x| ShimToken(f32x4(_mm256_cvtpd_ps(x.0)), PhantomData)
+}
+
+implement! {
+    f64x4 => int ShimToken<i32x4, i32, Avx>, |x|
+        ShimToken(i32x4(_mm256_cvttpd_epi32(x.0)), PhantomData),
+        ShimToken(i32x4(_mm256_cvtpd_epi32(x.0)), PhantomData),
+        ShimToken(i32x4(saturate_pd256(x.0)), PhantomData)
+}
+
+implement! {
+    f32x8 => Shim<f64x4, f64, 2>, |x| Shim(
+        [
+            f64x4(_mm256_cvtps_pd(_mm256_castps256_ps128(x.0))),
+            f64x4(_mm256_cvtps_pd(_mm256_extractf128_ps(x.0, 1))),
+        ],
+        PhantomData,
+    )
+}
+
+implement! {
+    Shim<f64x4, f64, 2> => f32x8, |x| f32x8(_mm256_insertf128_ps(
+        _mm256_castps128_ps256(_mm256_cvtpd_ps((x.0[0]).0)),
+        _mm256_cvtpd_ps((x.0[1]).0),
+        1,
+    ))
+}
+
+implement! {
+    Shim<ShimToken<i32x4, i32, Avx>, i32, 2> => f32x8, |x| f32x8(_mm256_cvtepi32_ps(
+        _mm256_insertf128_si256(
+            _mm256_castsi128_si256(((x.0[0]).0).0),
+            ((x.0[1]).0).0,
+            1,
+        ),
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn split_epi32(x: __m256i) -> Shim<ShimToken<i32x4, i32, Avx>, i32, 2> {
+    Shim(
+        [
+            ShimToken(i32x4(_mm256_castsi256_si128(x)), PhantomData),
+            ShimToken(i32x4(_mm256_extractf128_si256(x, 1)), PhantomData),
+        ],
+        PhantomData,
+    )
+}
+
+implement! {
+    f32x8 => int Shim<ShimToken<i32x4, i32, Avx>, i32, 2>, |x|
+        split_epi32(_mm256_cvttps_epi32(x.0)),
+        split_epi32(_mm256_cvtps_epi32(x.0)),
+        split_epi32(saturate_ps256(x.0))
+}
@@ -0,0 +1,133 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{arch::x86::*, vector::Horizontal};
+
+macro_rules! implement {
+    {
+        $vector:ty, pairs -> $pairs:path $(, sum -> $sum:path)? $(, dot -> $dot:path)?
+    } => {
+        impl Horizontal for $vector {
+            #[inline]
+            fn horizontal_add_pairs(self, rhs: Self) -> Self {
+                Self(unsafe { $pairs(self.0, rhs.0) })
+            }
+
+            $(
+            #[inline]
+            fn horizontal_sum(self) -> Self::Scalar {
+                unsafe { $sum(self.0) }
+            }
+            )?
+
+            $(
+            #[inline]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                unsafe { $dot(self.0, rhs.0) }
+            }
+            )?
+        }
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn sum_ps(x: __m128) -> f32 {
+    let x = _mm_hadd_ps(x, x);
+    _mm_cvtss_f32(_mm_hadd_ps(x, x))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn dot_ps(a: __m128, b: __m128) -> f32 {
+    _mm_cvtss_f32(_mm_dp_ps(a, b, 0xf1))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn sum_pd(x: __m128d) -> f64 {
+    _mm_cvtsd_f64(_mm_hadd_pd(x, x))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn dot_pd(a: __m128d, b: __m128d) -> f64 {
+    _mm_cvtsd_f64(_mm_dp_pd(a, b, 0x31))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn sum_epi32(x: __m128i) -> i32 {
+    let x = _mm_hadd_epi32(x, x);
+    _mm_cvtsi128_si32(_mm_hadd_epi32(x, x))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn sum_epu32(x: __m128i) -> u32 {
+    sum_epi32(x) as u32
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn add_pairs_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_add_epi64(_mm_unpacklo_epi64(a, b), _mm_unpackhi_epi64(a, b))
+}
+
+// AVX horizontal adds operate within 128-bit halves, so gather the halves of each input before
+// adding.
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn add_pairs_ps256(a: __m256, b: __m256) -> __m256 {
+    _mm256_hadd_ps(
+        _mm256_permute2f128_ps(a, b, 0x20),
+        _mm256_permute2f128_ps(a, b, 0x31),
+    )
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn sum_ps256(x: __m256) -> f32 {
+    sum_ps(_mm_add_ps(
+        _mm256_castps256_ps128(x),
+        _mm256_extractf128_ps(x, 1),
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn dot_ps256(a: __m256, b: __m256) -> f32 {
+    let x = _mm256_dp_ps(a, b, 0xf1);
+    _mm_cvtss_f32(_mm_add_ss(
+        _mm256_castps256_ps128(x),
+        _mm256_extractf128_ps(x, 1),
+    ))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn add_pairs_pd256(a: __m256d, b: __m256d) -> __m256d {
+    _mm256_hadd_pd(
+        _mm256_permute2f128_pd(a, b, 0x20),
+        _mm256_permute2f128_pd(a, b, 0x31),
+    )
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn sum_pd256(x: __m256d) -> f64 {
+    sum_pd(_mm_add_pd(
+        _mm256_castpd256_pd128(x),
+        _mm256_extractf128_pd(x, 1),
+    ))
+}
+
+implement! { f32x4, pairs -> _mm_hadd_ps, sum -> sum_ps, dot -> dot_ps }
+implement! { f64x2, pairs -> _mm_hadd_pd, sum -> sum_pd, dot -> dot_pd }
+implement! { i32x4, pairs -> _mm_hadd_epi32, sum -> sum_epi32 }
+implement! { u32x4, pairs -> _mm_hadd_epi32, sum -> sum_epu32 }
+implement! { u64x2, pairs -> add_pairs_epi64 }
+implement! { f32x8, pairs -> add_pairs_ps256, sum -> sum_ps256, dot -> dot_ps256 }
+implement! { f64x4, pairs -> add_pairs_pd256, sum -> sum_pd256 }
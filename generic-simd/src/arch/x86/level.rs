@@ -0,0 +1,36 @@
+use crate::{
+    arch::x86::*,
+    scalar::Scalar,
+    shim::ShimToken,
+    vector::{width, Native},
+};
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+// The microarchitecture levels use the vectors of the best token they include, with the level's
+// additional features enabled in dispatched functions.
+macro_rules! implement {
+    { $level:ty => $base:ty: $($scalar:ty),* } => {
+        $(
+            impl Native<$level> for $scalar {
+                type Width = <$scalar as Native<$base>>::Width;
+            }
+
+            impl<const N: usize> Scalar<$level, width::Lanes<N>> for $scalar
+            where
+                $scalar: Scalar<$base, width::Lanes<N>>,
+            {
+                type Vector = ShimToken<<$scalar as Scalar<$base, width::Lanes<N>>>::Vector, $scalar, $level>;
+            }
+        )*
+    };
+}
+
+implement! { X86V2 => Sse: f32, f64, i32, u32, u64 }
+implement! { X86V3 => Avx: f32, f64, i32, u32, u64 }
+
+#[cfg(feature = "complex")]
+implement! { X86V2 => Sse: Complex<f32>, Complex<f64> }
+#[cfg(feature = "complex")]
+implement! { X86V3 => Avx: Complex<f32>, Complex<f64> }
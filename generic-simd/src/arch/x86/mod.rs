@@ -1,15 +1,55 @@
 //! x86/x86-64 vector types.
 
+macro_rules! implement_hints {
+    { $stream:ident } => {
+        #[inline]
+        unsafe fn write_stream_aligned_ptr(self, to: *mut Self::Scalar) {
+            $stream(to as *mut _, self.0);
+        }
+
+        #[inline]
+        fn stream_fence(_: Self::Token) {
+            unsafe { _mm_sfence() }
+        }
+
+        #[inline]
+        fn prefetch_read_ptr(
+            _: Self::Token,
+            from: *const Self::Scalar,
+            locality: crate::pointer::Locality,
+        ) {
+            unsafe { crate::arch::x86::prefetch(from as *const i8, locality) }
+        }
+
+        #[inline]
+        fn prefetch_write_ptr(
+            _: Self::Token,
+            to: *const Self::Scalar,
+            locality: crate::pointer::Locality,
+        ) {
+            unsafe { crate::arch::x86::prefetch(to as *const i8, locality) }
+        }
+    }
+}
+
 #[cfg(feature = "complex")]
 mod complex;
 #[cfg(feature = "complex")]
 pub use complex::*;
 
+mod classify;
+mod convert;
+mod horizontal;
+mod level;
+mod scan;
+
+pub mod sse2;
+
 use crate::{
     arch::{generic, Token},
-    scalar::Scalar,
-    shim::{Shim2, Shim4, ShimToken},
-    vector::{width, Native, Vector},
+    pointer::Locality,
+    shim::{Shim, ShimToken},
+    vector::Vector,
 };
 
 #[cfg(target_arch = "x86")]
@@ -17,6 +57,12 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+/// SSE2 instruction set token.
+///
+/// SSE2 is part of the x86-64 baseline, so on x86-64 this token is always available.
+#[derive(Copy, Clone, Debug)]
+pub struct Sse2(());
+
 /// SSE4.1 instruction set token.
 #[derive(Copy, Clone, Debug)]
 pub struct Sse(());
@@ -25,8 +71,39 @@ pub struct Sse(());
 #[derive(Copy, Clone, Debug)]
 pub struct Avx(());
 
-impl_token! { Sse => "sse4.1" }
-impl_token! { Avx => "avx" }
+/// x86-64-v2 microarchitecture level token.
+///
+/// This level includes SSE4.2 and POPCNT, and uses the SSE4.1 vectors.
+#[derive(Copy, Clone, Debug)]
+pub struct X86V2(());
+
+/// x86-64-v3 microarchitecture level token.
+///
+/// This level includes AVX2, BMI1, BMI2, F16C, FMA, and LZCNT, and uses the AVX vectors.
+#[derive(Copy, Clone, Debug)]
+pub struct X86V3(());
+
+/// FMA instruction set token.
+///
+/// This token doesn't provide any vectors, and is intended for composite tokens such as
+/// `(Avx, Fma)`.
+#[derive(Copy, Clone, Debug)]
+pub struct Fma(());
+
+/// F16C instruction set token.
+///
+/// This token doesn't provide any vectors, and is intended for composite tokens such as
+/// `(Avx, F16c)`.
+#[derive(Copy, Clone, Debug)]
+pub struct F16c(());
+
+arch_token! { Sse2 => "sse2" }
+arch_token! { Sse => "sse4.1" }
+arch_token! { Avx => "avx" }
+arch_token! { X86V2 => "sse3", "ssse3", "sse4.1", "sse4.2", "popcnt" }
+arch_token! { X86V3 => "avx2", "bmi1", "bmi2", "f16c", "fma", "lzcnt", "popcnt" }
+crate::impl_token! { unsafe Fma => "fma" }
+crate::impl_token! { unsafe F16c => "f16c" }
 
 impl core::convert::From<Avx> for Sse {
     #[inline]
@@ -35,22 +112,177 @@ impl core::convert::From<Avx> for Sse {
     }
 }
 
-impl Native<Sse> for f32 {
-    type Width = width::W4;
+impl core::convert::From<X86V2> for Sse {
+    #[inline]
+    fn from(_: X86V2) -> Sse {
+        unsafe { Sse::new_unchecked() }
+    }
 }
 
-impl Native<Sse> for f64 {
-    type Width = width::W2;
+impl core::convert::From<X86V2> for Sse2 {
+    #[inline]
+    fn from(_: X86V2) -> Sse2 {
+        unsafe { Sse2::new_unchecked() }
+    }
 }
 
-impl Native<Avx> for f32 {
-    type Width = width::W8;
+impl core::convert::From<X86V3> for X86V2 {
+    #[inline]
+    fn from(_: X86V3) -> X86V2 {
+        unsafe { X86V2::new_unchecked() }
+    }
 }
 
-impl Native<Avx> for f64 {
-    type Width = width::W4;
+impl core::convert::From<X86V3> for Avx {
+    #[inline]
+    fn from(_: X86V3) -> Avx {
+        unsafe { Avx::new_unchecked() }
+    }
 }
 
+impl core::convert::From<X86V3> for Sse {
+    #[inline]
+    fn from(_: X86V3) -> Sse {
+        unsafe { Sse::new_unchecked() }
+    }
+}
+
+impl core::convert::From<X86V3> for Sse2 {
+    #[inline]
+    fn from(_: X86V3) -> Sse2 {
+        unsafe { Sse2::new_unchecked() }
+    }
+}
+
+impl core::convert::From<X86V3> for Fma {
+    #[inline]
+    fn from(_: X86V3) -> Fma {
+        unsafe { Fma::new_unchecked() }
+    }
+}
+
+impl core::convert::From<X86V3> for F16c {
+    #[inline]
+    fn from(_: X86V3) -> F16c {
+        unsafe { F16c::new_unchecked() }
+    }
+}
+
+impl core::convert::From<Sse> for Sse2 {
+    #[inline]
+    fn from(_: Sse) -> Sse2 {
+        unsafe { Sse2::new_unchecked() }
+    }
+}
+
+impl core::convert::From<Avx> for Sse2 {
+    #[inline]
+    fn from(_: Avx) -> Sse2 {
+        unsafe { Sse2::new_unchecked() }
+    }
+}
+
+// SSE only provides read prefetches, which are also used for write hints.
+#[inline]
+unsafe fn prefetch(p: *const i8, locality: Locality) {
+    match locality {
+        Locality::None => _mm_prefetch(p, _MM_HINT_NTA),
+        Locality::Low => _mm_prefetch(p, _MM_HINT_T2),
+        Locality::Moderate => _mm_prefetch(p, _MM_HINT_T1),
+        Locality::High => _mm_prefetch(p, _MM_HINT_T0),
+    }
+}
+
+// SSE only provides 32-bit multiplies, so combine the partial products of the halves.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn mullo_epi64(a: __m128i, b: __m128i) -> __m128i {
+    let low = _mm_mul_epu32(a, b);
+    let cross = _mm_add_epi64(
+        _mm_mul_epu32(_mm_srli_epi64(a, 32), b),
+        _mm_mul_epu32(a, _mm_srli_epi64(b, 32)),
+    );
+    _mm_add_epi64(low, _mm_slli_epi64(cross, 32))
+}
+
+// SSE and AVX only provide `!a & b`, and have no bitwise not.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn not_ps(x: __m128) -> __m128 {
+    _mm_xor_ps(x, _mm_castsi128_ps(_mm_set1_epi32(-1)))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn not_pd(x: __m128d) -> __m128d {
+    _mm_xor_pd(x, _mm_castsi128_pd(_mm_set1_epi32(-1)))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn not_ps256(x: __m256) -> __m256 {
+    _mm256_xor_ps(x, _mm256_castsi256_ps(_mm256_set1_epi32(-1)))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn not_pd256(x: __m256d) -> __m256d {
+    _mm256_xor_pd(x, _mm256_castsi256_pd(_mm256_set1_epi32(-1)))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn and_not_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_andnot_ps(b, a)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn and_not_pd(a: __m128d, b: __m128d) -> __m128d {
+    _mm_andnot_pd(b, a)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn and_not_ps256(a: __m256, b: __m256) -> __m256 {
+    _mm256_andnot_ps(b, a)
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn and_not_pd256(a: __m256d, b: __m256d) -> __m256d {
+    _mm256_andnot_pd(b, a)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn not_si128(x: __m128i) -> __m128i {
+    _mm_xor_si128(x, _mm_set1_epi32(-1))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn and_not_si128(a: __m128i, b: __m128i) -> __m128i {
+    _mm_andnot_si128(b, a)
+}
+
+// SSE shifts by a count in a register saturate when the count is at least the number of bits.
+macro_rules! shift {
+    { $name:ident, $intrinsic:ident } => {
+        #[target_feature(enable = "sse4.1")]
+        #[inline]
+        unsafe fn $name(a: __m128i, count: u32) -> __m128i {
+            $intrinsic(a, _mm_cvtsi32_si128(count as i32))
+        }
+    }
+}
+
+shift! { sll_epi32, _mm_sll_epi32 }
+shift! { srl_epi32, _mm_srl_epi32 }
+shift! { sra_epi32, _mm_sra_epi32 }
+shift! { sll_epi64, _mm_sll_epi64 }
+shift! { srl_epi64, _mm_srl_epi64 }
+
 /// An SSE vector of `f32`s.
 #[derive(Clone, Copy, Debug)]
 #[repr(transparent)]
@@ -63,6 +295,24 @@ pub struct f32x4(__m128);
 #[allow(non_camel_case_types)]
 pub struct f64x2(__m128d);
 
+/// An SSE vector of `i32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x4(__m128i);
+
+/// An SSE vector of `u32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x4(__m128i);
+
+/// An SSE vector of `u64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x2(__m128i);
+
 /// An AVX vector of `f32`s.
 #[derive(Clone, Copy, Debug)]
 #[repr(transparent)]
@@ -75,104 +325,219 @@ pub struct f32x8(__m256);
 #[allow(non_camel_case_types)]
 pub struct f64x4(__m256d);
 
-impl Scalar<Sse, width::W1> for f32 {
-    type Vector = ShimToken<generic::f32x1, Self, Sse>;
+impl_scalar! {
+    Sse, f32, native 4 {
+        1 => ShimToken<generic::f32x1, Self, Sse>,
+        2 => ShimToken<Shim<generic::f32x1, Self, 2>, Self, Sse>,
+    }
+    4.. => f32x4
 }
 
-impl Scalar<Sse, width::W2> for f32 {
-    type Vector = ShimToken<Shim2<generic::f32x1, Self>, Self, Sse>;
+impl_scalar! {
+    Sse, f64, native 2 {
+        1 => ShimToken<generic::f64x1, Self, Sse>,
+    }
+    2.. => f64x2
 }
 
-impl Scalar<Sse, width::W4> for f32 {
-    type Vector = f32x4;
+impl_scalar! {
+    Avx, f32, native 8 {
+        1 => ShimToken<generic::f32x1, Self, Avx>,
+        2 => ShimToken<Shim<generic::f32x1, Self, 2>, Self, Avx>,
+        4 => ShimToken<f32x4, Self, Avx>,
+    }
+    8.. => f32x8
 }
 
-impl Scalar<Sse, width::W8> for f32 {
-    type Vector = Shim2<f32x4, f32>;
+impl_scalar! {
+    Avx, f64, native 4 {
+        1 => ShimToken<generic::f64x1, Self, Avx>,
+        2 => ShimToken<f64x2, Self, Avx>,
+    }
+    4.. => f64x4
 }
 
-impl Scalar<Sse, width::W1> for f64 {
-    type Vector = ShimToken<generic::f64x1, Self, Sse>;
+impl_scalar! {
+    Sse, i32, native 4 {
+        1 => ShimToken<generic::i32x1, Self, Sse>,
+        2 => ShimToken<Shim<generic::i32x1, Self, 2>, Self, Sse>,
+    }
+    4.. => i32x4
 }
 
-impl Scalar<Sse, width::W2> for f64 {
-    type Vector = f64x2;
+impl_scalar! {
+    Avx, i32, native 4 {
+        1 => ShimToken<generic::i32x1, Self, Avx>,
+        2 => ShimToken<Shim<generic::i32x1, Self, 2>, Self, Avx>,
+    }
+    4.. => ShimToken<i32x4, Self, Avx>
 }
 
-impl Scalar<Sse, width::W4> for f64 {
-    type Vector = Shim2<f64x2, f64>;
+impl_scalar! {
+    Sse, u32, native 4 {
+        1 => ShimToken<generic::u32x1, Self, Sse>,
+        2 => ShimToken<Shim<generic::u32x1, Self, 2>, Self, Sse>,
+    }
+    4.. => u32x4
 }
 
-impl Scalar<Sse, width::W8> for f64 {
-    type Vector = Shim4<f64x2, f64>;
+impl_scalar! {
+    Avx, u32, native 4 {
+        1 => ShimToken<generic::u32x1, Self, Avx>,
+        2 => ShimToken<Shim<generic::u32x1, Self, 2>, Self, Avx>,
+    }
+    4.. => ShimToken<u32x4, Self, Avx>
 }
 
-impl Scalar<Avx, width::W1> for f32 {
-    type Vector = ShimToken<generic::f32x1, Self, Avx>;
+impl_scalar! {
+    Sse, u64, native 2 {
+        1 => ShimToken<generic::u64x1, Self, Sse>,
+    }
+    2.. => u64x2
 }
 
-impl Scalar<Avx, width::W2> for f32 {
-    type Vector = ShimToken<Shim2<generic::f32x1, Self>, Self, Avx>;
+impl_scalar! {
+    Avx, u64, native 2 {
+        1 => ShimToken<generic::u64x1, Self, Avx>,
+    }
+    2.. => ShimToken<u64x2, Self, Avx>
 }
 
-impl Scalar<Avx, width::W4> for f32 {
-    type Vector = ShimToken<f32x4, Self, Avx>;
+arithmetic_ops! {
+    for f32x4:
+        add -> (unsafe _mm_add_ps),
+        sub -> (unsafe _mm_sub_ps),
+        mul -> (unsafe _mm_mul_ps),
+        div -> (unsafe _mm_div_ps)
 }
 
-impl Scalar<Avx, width::W8> for f32 {
-    type Vector = f32x8;
+arithmetic_ops! {
+    for f64x2:
+        add -> (unsafe _mm_add_pd),
+        sub -> (unsafe _mm_sub_pd),
+        mul -> (unsafe _mm_mul_pd),
+        div -> (unsafe _mm_div_pd)
 }
 
-impl Scalar<Avx, width::W1> for f64 {
-    type Vector = ShimToken<generic::f64x1, Self, Avx>;
+arithmetic_ops! {
+    for i32x4:
+        add -> (unsafe _mm_add_epi32),
+        sub -> (unsafe _mm_sub_epi32),
+        mul -> (unsafe _mm_mullo_epi32),
+        div -> ()
 }
 
-impl Scalar<Avx, width::W2> for f64 {
-    type Vector = ShimToken<f64x2, Self, Avx>;
+arithmetic_ops! {
+    for u32x4:
+        add -> (unsafe _mm_add_epi32),
+        sub -> (unsafe _mm_sub_epi32),
+        mul -> (unsafe _mm_mullo_epi32),
+        div -> ()
 }
 
-impl Scalar<Avx, width::W4> for f64 {
-    type Vector = f64x4;
+arithmetic_ops! {
+    for u64x2:
+        add -> (unsafe _mm_add_epi64),
+        sub -> (unsafe _mm_sub_epi64),
+        mul -> (unsafe mullo_epi64),
+        div -> ()
 }
 
-impl Scalar<Avx, width::W8> for f64 {
-    type Vector = Shim2<f64x4, f64>;
+arithmetic_ops! {
+    for f32x8:
+        add -> (unsafe _mm256_add_ps),
+        sub -> (unsafe _mm256_sub_ps),
+        mul -> (unsafe _mm256_mul_ps),
+        div -> (unsafe _mm256_div_ps)
 }
 
 arithmetic_ops! {
-    feature: Sse::new_unchecked(),
+    for f64x4:
+        add -> (unsafe _mm256_add_pd),
+        sub -> (unsafe _mm256_sub_pd),
+        mul -> (unsafe _mm256_mul_pd),
+        div -> (unsafe _mm256_div_pd)
+}
+
+bitwise_ops! {
     for f32x4:
-        add -> (_mm_add_ps),
-        sub -> (_mm_sub_ps),
-        mul -> (_mm_mul_ps),
-        div -> (_mm_div_ps)
+        and -> (unsafe _mm_and_ps),
+        or -> (unsafe _mm_or_ps),
+        xor -> (unsafe _mm_xor_ps),
+        not -> (unsafe not_ps),
+        and_not -> (unsafe and_not_ps)
 }
 
-arithmetic_ops! {
-    feature: Sse::new_unchecked(),
+bitwise_ops! {
     for f64x2:
-        add -> (_mm_add_pd),
-        sub -> (_mm_sub_pd),
-        mul -> (_mm_mul_pd),
-        div -> (_mm_div_pd)
+        and -> (unsafe _mm_and_pd),
+        or -> (unsafe _mm_or_pd),
+        xor -> (unsafe _mm_xor_pd),
+        not -> (unsafe not_pd),
+        and_not -> (unsafe and_not_pd)
 }
 
-arithmetic_ops! {
-    feature: Avx::new_unchecked(),
+bitwise_ops! {
     for f32x8:
-        add -> (_mm256_add_ps),
-        sub -> (_mm256_sub_ps),
-        mul -> (_mm256_mul_ps),
-        div -> (_mm256_div_ps)
+        and -> (unsafe _mm256_and_ps),
+        or -> (unsafe _mm256_or_ps),
+        xor -> (unsafe _mm256_xor_ps),
+        not -> (unsafe not_ps256),
+        and_not -> (unsafe and_not_ps256)
 }
 
-arithmetic_ops! {
-    feature: Avx::new_unchecked(),
+bitwise_ops! {
     for f64x4:
-        add -> (_mm256_add_pd),
-        sub -> (_mm256_sub_pd),
-        mul -> (_mm256_mul_pd),
-        div -> (_mm256_div_pd)
+        and -> (unsafe _mm256_and_pd),
+        or -> (unsafe _mm256_or_pd),
+        xor -> (unsafe _mm256_xor_pd),
+        not -> (unsafe not_pd256),
+        and_not -> (unsafe and_not_pd256)
+}
+
+bitwise_ops! {
+    for i32x4:
+        and -> (unsafe _mm_and_si128),
+        or -> (unsafe _mm_or_si128),
+        xor -> (unsafe _mm_xor_si128),
+        not -> (unsafe not_si128),
+        and_not -> (unsafe and_not_si128)
+}
+
+shift_ops! {
+    for i32x4:
+        shl -> (unsafe sll_epi32),
+        shr -> (unsafe sra_epi32)
+}
+
+bitwise_ops! {
+    for u32x4:
+        and -> (unsafe _mm_and_si128),
+        or -> (unsafe _mm_or_si128),
+        xor -> (unsafe _mm_xor_si128),
+        not -> (unsafe not_si128),
+        and_not -> (unsafe and_not_si128)
+}
+
+shift_ops! {
+    for u32x4:
+        shl -> (unsafe sll_epi32),
+        shr -> (unsafe srl_epi32)
+}
+
+bitwise_ops! {
+    for u64x2:
+        and -> (unsafe _mm_and_si128),
+        or -> (unsafe _mm_or_si128),
+        xor -> (unsafe _mm_xor_si128),
+        not -> (unsafe not_si128),
+        and_not -> (unsafe and_not_si128)
+}
+
+shift_ops! {
+    for u64x2:
+        shl -> (unsafe sll_epi64),
+        shr -> (unsafe srl_epi64)
 }
 
 impl core::ops::Neg for f32x4 {
@@ -193,6 +558,15 @@ impl core::ops::Neg for f64x2 {
     }
 }
 
+impl core::ops::Neg for i32x4 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm_sub_epi32(_mm_setzero_si128(), self.0) })
+    }
+}
+
 impl core::ops::Neg for f32x8 {
     type Output = Self;
 
@@ -215,6 +589,9 @@ as_slice! { f32x4 }
 as_slice! { f32x8 }
 as_slice! { f64x2 }
 as_slice! { f64x4 }
+as_slice! { i32x4 }
+as_slice! { u32x4 }
+as_slice! { u64x2 }
 
 unsafe impl Vector for f32x4 {
     type Scalar = f32;
@@ -234,6 +611,8 @@ unsafe impl Vector for f32x4 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm_set1_ps(from) })
     }
+
+    implement_hints! { _mm_stream_ps }
 }
 
 unsafe impl Vector for f64x2 {
@@ -254,6 +633,8 @@ unsafe impl Vector for f64x2 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm_set1_pd(from) })
     }
+
+    implement_hints! { _mm_stream_pd }
 }
 
 unsafe impl Vector for f32x8 {
@@ -274,6 +655,8 @@ unsafe impl Vector for f32x8 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm256_set1_ps(from) })
     }
+
+    implement_hints! { _mm256_stream_ps }
 }
 
 unsafe impl Vector for f64x4 {
@@ -294,4 +677,72 @@ unsafe impl Vector for f64x4 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm256_set1_pd(from) })
     }
+
+    implement_hints! { _mm256_stream_pd }
+}
+
+unsafe impl Vector for i32x4 {
+    type Scalar = i32;
+
+    type Token = Sse;
+
+    type Width = crate::vector::width::W4;
+
+    type Underlying = __m128i;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm_setzero_si128() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm_set1_epi32(from) })
+    }
+
+    implement_hints! { _mm_stream_si128 }
+}
+
+unsafe impl Vector for u32x4 {
+    type Scalar = u32;
+
+    type Token = Sse;
+
+    type Width = crate::vector::width::W4;
+
+    type Underlying = __m128i;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm_setzero_si128() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm_set1_epi32(from as i32) })
+    }
+
+    implement_hints! { _mm_stream_si128 }
+}
+
+unsafe impl Vector for u64x2 {
+    type Scalar = u64;
+
+    type Token = Sse;
+
+    type Width = crate::vector::width::W2;
+
+    type Underlying = __m128i;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm_setzero_si128() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm_set1_epi64x(from as i64) })
+    }
+
+    implement_hints! { _mm_stream_si128 }
 }
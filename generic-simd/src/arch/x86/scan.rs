@@ -0,0 +1,136 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{arch::x86::*, vector::PrefixSum};
+
+macro_rules! implement {
+    {
+        $vector:ty, $inclusive:ident, $exclusive:ident
+    } => {
+        impl PrefixSum for $vector {
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                Self(unsafe { $inclusive(self.0) })
+            }
+
+            #[inline]
+            fn exclusive_prefix_sum(self) -> Self {
+                Self(unsafe { $inclusive($exclusive(self.0)) })
+            }
+        }
+    }
+}
+
+// 128-bit vectors shift lanes up by whole bytes, shifting in zeros.
+macro_rules! shift_ps {
+    { $x:expr, $bytes:literal } => {
+        _mm_castsi128_ps(_mm_slli_si128(_mm_castps_si128($x), $bytes))
+    }
+}
+
+macro_rules! shift_pd {
+    { $x:expr, $bytes:literal } => {
+        _mm_castsi128_pd(_mm_slli_si128(_mm_castpd_si128($x), $bytes))
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn prefix_sum_ps(x: __m128) -> __m128 {
+    let x = _mm_add_ps(x, shift_ps!(x, 4));
+    _mm_add_ps(x, shift_ps!(x, 8))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn shift_lane_ps(x: __m128) -> __m128 {
+    shift_ps!(x, 4)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn prefix_sum_pd(x: __m128d) -> __m128d {
+    _mm_add_pd(x, shift_pd!(x, 8))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn shift_lane_pd(x: __m128d) -> __m128d {
+    shift_pd!(x, 8)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn prefix_sum_epi32(x: __m128i) -> __m128i {
+    let x = _mm_add_epi32(x, _mm_slli_si128(x, 4));
+    _mm_add_epi32(x, _mm_slli_si128(x, 8))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn shift_lane_epi32(x: __m128i) -> __m128i {
+    _mm_slli_si128(x, 4)
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn prefix_sum_epi64(x: __m128i) -> __m128i {
+    _mm_add_epi64(x, _mm_slli_si128(x, 8))
+}
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn shift_lane_epi64(x: __m128i) -> __m128i {
+    _mm_slli_si128(x, 8)
+}
+
+// AVX permutes only within 128-bit halves, so scan each half and then carry the total of the low
+// half into the high half.
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn prefix_sum_ps256(x: __m256) -> __m256 {
+    let zero = _mm256_setzero_ps();
+    let x = _mm256_add_ps(x, _mm256_blend_ps(_mm256_permute_ps(x, 0x93), zero, 0x11));
+    let x = _mm256_add_ps(x, _mm256_blend_ps(_mm256_permute_ps(x, 0x4e), zero, 0x33));
+    let total = _mm256_permute_ps(x, 0xff);
+    _mm256_add_ps(x, _mm256_permute2f128_ps(total, total, 0x08))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn shift_lane_ps256(x: __m256) -> __m256 {
+    let rotated = _mm256_permute_ps(x, 0x93);
+    _mm256_blend_ps(
+        rotated,
+        _mm256_permute2f128_ps(rotated, rotated, 0x08),
+        0x11,
+    )
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn prefix_sum_pd256(x: __m256d) -> __m256d {
+    let x = _mm256_add_pd(
+        x,
+        _mm256_blend_pd(_mm256_permute_pd(x, 0x5), _mm256_setzero_pd(), 0x5),
+    );
+    let total = _mm256_permute_pd(x, 0xf);
+    _mm256_add_pd(x, _mm256_permute2f128_pd(total, total, 0x08))
+}
+
+#[target_feature(enable = "avx")]
+#[inline]
+unsafe fn shift_lane_pd256(x: __m256d) -> __m256d {
+    let rotated = _mm256_permute_pd(x, 0x5);
+    _mm256_blend_pd(rotated, _mm256_permute2f128_pd(rotated, rotated, 0x08), 0x5)
+}
+
+implement! { f32x4, prefix_sum_ps, shift_lane_ps }
+implement! { f64x2, prefix_sum_pd, shift_lane_pd }
+implement! { i32x4, prefix_sum_epi32, shift_lane_epi32 }
+implement! { u32x4, prefix_sum_epi32, shift_lane_epi32 }
+implement! { u64x2, prefix_sum_epi64, shift_lane_epi64 }
+implement! { f32x8, prefix_sum_ps256, shift_lane_ps256 }
+implement! { f64x4, prefix_sum_pd256, shift_lane_pd256 }
@@ -0,0 +1,207 @@
+//! SSE2 vector types.
+//!
+//! SSE2 is available on every x86-64 CPU, so these vectors are used when SSE4.1 is not.
+//! Only floating-point vectors are native, and other scalars use shims of generic vectors.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    arch::{generic, x86::Sse2},
+    shim::{Shim, ShimToken},
+    vector::{Classify, Horizontal, PrefixSum, Vector},
+};
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+/// An SSE2 vector of `f32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f32x4(__m128);
+
+/// An SSE2 vector of `f64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f64x2(__m128d);
+
+impl_scalar! {
+    Sse2, f32, native 4 {
+        1 => ShimToken<generic::f32x1, Self, Sse2>,
+        2 => ShimToken<Shim<generic::f32x1, Self, 2>, Self, Sse2>,
+    }
+    4.. => f32x4
+}
+
+impl_scalar! {
+    Sse2, f64, native 2 {
+        1 => ShimToken<generic::f64x1, Self, Sse2>,
+    }
+    2.. => f64x2
+}
+
+macro_rules! shim_scalar {
+    { $scalar:ty, $generic:ty, native $native:tt } => {
+        impl_scalar! {
+            Sse2, $scalar, native $native {}
+            1.. => ShimToken<$generic, Self, Sse2>
+        }
+    }
+}
+
+shim_scalar! { i32, generic::i32x1, native 4 }
+shim_scalar! { u32, generic::u32x1, native 4 }
+shim_scalar! { u64, generic::u64x1, native 2 }
+
+#[cfg(feature = "complex")]
+shim_scalar! { Complex<f32>, generic::cf32x1, native 1 }
+#[cfg(feature = "complex")]
+shim_scalar! { Complex<f64>, generic::cf64x1, native 1 }
+
+convert_lanes! { ShimToken<Shim<generic::f32x1, f32, 2>, f32, Sse2> => f64x2 }
+convert_lanes! { ShimToken<Shim<generic::f32x1, f32, 2>, f32, Sse2> => int Shim<ShimToken<generic::i32x1, i32, Sse2>, i32, 2> }
+convert_lanes! { f64x2 => ShimToken<Shim<generic::f32x1, f32, 2>, f32, Sse2> }
+convert_lanes! { f64x2 => int Shim<ShimToken<generic::i32x1, i32, Sse2>, i32, 2> }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Sse2>, i32, 2> => ShimToken<Shim<generic::f32x1, f32, 2>, f32, Sse2> }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Sse2>, i32, 2> => f64x2 }
+convert_lanes! { f32x4 => Shim<f64x2, f64, 2> }
+convert_lanes! { f32x4 => int Shim<ShimToken<generic::i32x1, i32, Sse2>, i32, 4> }
+convert_lanes! { Shim<f64x2, f64, 2> => f32x4 }
+convert_lanes! { Shim<ShimToken<generic::i32x1, i32, Sse2>, i32, 4> => f32x4 }
+
+// SSE2 only provides `!a & b`, and has no bitwise not.
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn not_ps(x: __m128) -> __m128 {
+    _mm_xor_ps(x, _mm_castsi128_ps(_mm_set1_epi32(-1)))
+}
+
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn not_pd(x: __m128d) -> __m128d {
+    _mm_xor_pd(x, _mm_castsi128_pd(_mm_set1_epi32(-1)))
+}
+
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn and_not_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_andnot_ps(b, a)
+}
+
+#[target_feature(enable = "sse2")]
+#[inline]
+unsafe fn and_not_pd(a: __m128d, b: __m128d) -> __m128d {
+    _mm_andnot_pd(b, a)
+}
+
+arithmetic_ops! {
+    for f32x4:
+        add -> (unsafe _mm_add_ps),
+        sub -> (unsafe _mm_sub_ps),
+        mul -> (unsafe _mm_mul_ps),
+        div -> (unsafe _mm_div_ps)
+}
+
+arithmetic_ops! {
+    for f64x2:
+        add -> (unsafe _mm_add_pd),
+        sub -> (unsafe _mm_sub_pd),
+        mul -> (unsafe _mm_mul_pd),
+        div -> (unsafe _mm_div_pd)
+}
+
+bitwise_ops! {
+    for f32x4:
+        and -> (unsafe _mm_and_ps),
+        or -> (unsafe _mm_or_ps),
+        xor -> (unsafe _mm_xor_ps),
+        not -> (unsafe not_ps),
+        and_not -> (unsafe and_not_ps)
+}
+
+bitwise_ops! {
+    for f64x2:
+        and -> (unsafe _mm_and_pd),
+        or -> (unsafe _mm_or_pd),
+        xor -> (unsafe _mm_xor_pd),
+        not -> (unsafe not_pd),
+        and_not -> (unsafe and_not_pd)
+}
+
+impl PrefixSum for f32x4 {}
+impl PrefixSum for f64x2 {}
+
+impl Horizontal for f32x4 {}
+impl Horizontal for f64x2 {}
+
+impl Classify for f32x4 {}
+impl Classify for f64x2 {}
+
+impl core::ops::Neg for f32x4 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm_xor_ps(self.0, _mm_set1_ps(-0.)) })
+    }
+}
+
+impl core::ops::Neg for f64x2 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm_xor_pd(self.0, _mm_set1_pd(-0.)) })
+    }
+}
+
+as_slice! { f32x4 }
+as_slice! { f64x2 }
+
+unsafe impl Vector for f32x4 {
+    type Scalar = f32;
+
+    type Token = Sse2;
+
+    type Width = crate::vector::width::W4;
+
+    type Underlying = __m128;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm_setzero_ps() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm_set1_ps(from) })
+    }
+
+    implement_hints! { _mm_stream_ps }
+}
+
+unsafe impl Vector for f64x2 {
+    type Scalar = f64;
+
+    type Token = Sse2;
+
+    type Width = crate::vector::width::W2;
+
+    type Underlying = __m128d;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm_setzero_pd() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm_set1_pd(from) })
+    }
+
+    implement_hints! { _mm_stream_pd }
+}
@@ -1,6 +1,39 @@
+/// Implements the arithmetic operators for a vector type.
+///
+/// This implements `Add`, `Sub`, `Mul` and `Div`, and their assigning variants, with both vector
+/// and scalar right-hand sides.
+/// It also implements `Sum` and `Product` for `Option<Self>` and for the scalar type.
+///
+/// Each operation is one of:
+/// * `(path)`, a safe function taking and returning the underlying type of a single-field tuple
+///   struct,
+/// * `(unsafe path)`, the same but an `unsafe` function, such as an intrinsic,
+/// * `()`, which applies the scalar operation to each lane, wrapping on integer overflow.
+///
+/// Vectors using `()` must implement `DerefMut` to a slice of scalars, such as with
+/// [`as_slice!`].
+///
+/// # Safety
+/// Functions given as `(unsafe path)` are called from safe code with any values of the
+/// underlying type.
+/// Writing `unsafe` asserts that this is sound, which is usually the case for intrinsics that
+/// only require CPU features guaranteed by the vector's token.
+///
+/// See the [`vector`](crate::vector) module for a complete example.
+#[macro_export]
 macro_rules! arithmetic_ops {
     {
-        @new $type:ty, $feature:expr, $trait:ident, $func:ident, ()
+        @call (unsafe $op:path), $($arg:expr),*
+    } => {
+        unsafe { $op($($arg),*) }
+    };
+    {
+        @call ($op:path), $($arg:expr),*
+    } => {
+        $op($($arg),*)
+    };
+    {
+        @new $type:ty, $trait:ident, $func:ident, ()
     } => {
         impl core::ops::$trait<$type> for $type {
             type Output = Self;
@@ -8,7 +41,7 @@ macro_rules! arithmetic_ops {
             #[inline]
             fn $func(mut self, rhs: Self) -> Self {
                 for (a, b) in self.iter_mut().zip(rhs.iter()) {
-                    *a = core::ops::$trait::$func(*a, b);
+                    *a = $crate::arch::generic::ScalarArithmetic::$func(*a, *b);
                 }
                 self
             }
@@ -19,21 +52,21 @@ macro_rules! arithmetic_ops {
             #[inline]
             fn $func(mut self, rhs: <$type as $crate::vector::Vector>::Scalar) -> Self {
                 for a in self.iter_mut() {
-                    *a = core::ops::$trait::$func(*a, rhs);
+                    *a = $crate::arch::generic::ScalarArithmetic::$func(*a, rhs);
                 }
                 self
             }
         }
     };
     {
-        @assign $type:ty, $feature:expr, $trait:ident, $func:ident, ()
+        @assign $type:ty, $trait:ident, $func:ident, ()
     } => {
         impl core::ops::$trait<$type> for $type {
             #[allow(unused_unsafe)]
             #[inline]
             fn $func(&mut self, rhs: Self) {
                 for (a, b) in self.iter_mut().zip(rhs.iter()) {
-                    core::ops::$trait::$func(a, b);
+                    $crate::arch::generic::ScalarArithmetic::$func(a, *b);
                 }
             }
         }
@@ -42,20 +75,20 @@ macro_rules! arithmetic_ops {
             #[inline]
             fn $func(&mut self, rhs: <$type as $crate::vector::Vector>::Scalar) {
                 for a in self.iter_mut() {
-                    core::ops::$trait::$func(a, rhs);
+                    $crate::arch::generic::ScalarArithmetic::$func(a, rhs);
                 }
             }
         }
     };
     {
-        @new $type:ty, $feature:expr, $trait:ident, $func:ident, ($op:path)
+        @new $type:ty, $trait:ident, $func:ident, $op:tt
     } => {
         impl core::ops::$trait<$type> for $type {
             type Output = Self;
             #[allow(unused_unsafe)]
             #[inline]
             fn $func(self, rhs: Self) -> Self {
-                Self(unsafe { $op(self.0, rhs.0) })
+                Self($crate::arithmetic_ops!(@call $op, self.0, rhs.0))
             }
         }
 
@@ -63,30 +96,29 @@ macro_rules! arithmetic_ops {
             type Output = Self;
             #[inline]
             fn $func(self, rhs: <$type as $crate::vector::Vector>::Scalar) -> Self {
-                self.$func(<$type>::splat(unsafe { $feature }, rhs))
+                self.$func(<$type>::splat($crate::vector::Vector::to_token(self), rhs))
             }
         }
     };
     {
-        @assign $type:ty, $feature:expr, $trait:ident, $func:ident, ($op:path)
+        @assign $type:ty, $trait:ident, $func:ident, $op:tt
     } => {
         impl core::ops::$trait<$type> for $type {
             #[allow(unused_unsafe)]
             #[inline]
             fn $func(&mut self, rhs: Self) {
-                self.0 = unsafe { $op(self.0, rhs.0) };
+                self.0 = $crate::arithmetic_ops!(@call $op, self.0, rhs.0);
             }
         }
 
         impl core::ops::$trait<<$type as $crate::vector::Vector>::Scalar> for $type {
             #[inline]
             fn $func(&mut self, rhs: <$type as $crate::vector::Vector>::Scalar) {
-                self.$func(<$type>::splat(unsafe { $feature }, rhs))
+                self.$func(<$type>::splat($crate::vector::Vector::to_token(*self), rhs))
             }
         }
     };
     {
-        feature: $feature:expr,
         for $type:ty:
             add -> $add_expr:tt,
             sub -> $sub_expr:tt,
@@ -117,7 +149,10 @@ macro_rules! arithmetic_ops {
                 I: Iterator<Item = $type>,
             {
                 if let Some(sums) = iter.sum::<Option<$type>>() {
-                    sums.iter().sum()
+                    sums.iter()
+                        .copied()
+                        .reduce($crate::arch::generic::ScalarArithmetic::add)
+                        .unwrap_or_default()
                 } else {
                     Default::default()
                 }
@@ -147,47 +182,279 @@ macro_rules! arithmetic_ops {
             where
                 I: Iterator<Item = $type>,
             {
-                if let Some(sums) = iter.sum::<Option<$type>>() {
-                    sums.iter().product()
+                if let Some(products) = iter.product::<Option<$type>>() {
+                    products.iter()
+                        .copied()
+                        .reduce($crate::arch::generic::ScalarArithmetic::mul)
+                        .unwrap_or_default()
                 } else {
                     Default::default()
                 }
             }
         }
 
-        arithmetic_ops!{@new $type, $feature, Add, add, $add_expr}
-        arithmetic_ops!{@new $type, $feature, Sub, sub, $sub_expr}
-        arithmetic_ops!{@new $type, $feature, Mul, mul, $mul_expr}
-        arithmetic_ops!{@new $type, $feature, Div, div, $div_expr}
-        arithmetic_ops!{@assign $type, $feature, AddAssign, add_assign, $add_expr}
-        arithmetic_ops!{@assign $type, $feature, SubAssign, sub_assign, $sub_expr}
-        arithmetic_ops!{@assign $type, $feature, MulAssign, mul_assign, $mul_expr}
-        arithmetic_ops!{@assign $type, $feature, DivAssign, div_assign, $div_expr}
+        $crate::arithmetic_ops!{@new $type, Add, add, $add_expr}
+        $crate::arithmetic_ops!{@new $type, Sub, sub, $sub_expr}
+        $crate::arithmetic_ops!{@new $type, Mul, mul, $mul_expr}
+        $crate::arithmetic_ops!{@new $type, Div, div, $div_expr}
+        $crate::arithmetic_ops!{@assign $type, AddAssign, add_assign, $add_expr}
+        $crate::arithmetic_ops!{@assign $type, SubAssign, sub_assign, $sub_expr}
+        $crate::arithmetic_ops!{@assign $type, MulAssign, mul_assign, $mul_expr}
+        $crate::arithmetic_ops!{@assign $type, DivAssign, div_assign, $div_expr}
+    };
+}
+
+/// Implements the bitwise operators for a vector.
+///
+/// This implements `BitAnd`, `BitOr`, `BitXor`, their assigning variants, `Not` and [`Bitwise`].
+/// As with [`arithmetic_ops!`], each operation is `(path)`, `(unsafe path)` or `()`, with the
+/// same safety requirements.
+/// Lanes of `f32` or `f64` are operated on as their bit patterns when using `()`.
+///
+/// [`Bitwise`]: crate::vector::Bitwise
+#[macro_export]
+macro_rules! bitwise_ops {
+    {
+        @call (unsafe $op:path), $($arg:expr),*
+    } => {
+        unsafe { $op($($arg),*) }
+    };
+    {
+        @call ($op:path), $($arg:expr),*
+    } => {
+        $op($($arg),*)
+    };
+    {
+        @new $type:ty, $trait:ident, $func:ident, ()
+    } => {
+        impl core::ops::$trait<$type> for $type {
+            type Output = Self;
+            #[inline]
+            fn $func(mut self, rhs: Self) -> Self {
+                use $crate::arch::generic::ScalarBits;
+                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                    *a = ScalarBits::from_bits(core::ops::$trait::$func(
+                        ScalarBits::to_bits(*a),
+                        ScalarBits::to_bits(*b),
+                    ));
+                }
+                self
+            }
+        }
+
+        impl core::ops::$trait<<$type as $crate::vector::Vector>::Scalar> for $type {
+            type Output = Self;
+            #[inline]
+            fn $func(mut self, rhs: <$type as $crate::vector::Vector>::Scalar) -> Self {
+                use $crate::arch::generic::ScalarBits;
+                for a in self.iter_mut() {
+                    *a = ScalarBits::from_bits(core::ops::$trait::$func(
+                        ScalarBits::to_bits(*a),
+                        ScalarBits::to_bits(rhs),
+                    ));
+                }
+                self
+            }
+        }
+    };
+    {
+        @new $type:ty, $trait:ident, $func:ident, $op:tt
+    } => {
+        impl core::ops::$trait<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn $func(self, rhs: Self) -> Self {
+                Self($crate::bitwise_ops!(@call $op, self.0, rhs.0))
+            }
+        }
+
+        impl core::ops::$trait<<$type as $crate::vector::Vector>::Scalar> for $type {
+            type Output = Self;
+            #[inline]
+            fn $func(self, rhs: <$type as $crate::vector::Vector>::Scalar) -> Self {
+                self.$func(<$type>::splat($crate::vector::Vector::to_token(self), rhs))
+            }
+        }
+    };
+    {
+        @assign $type:ty, $trait:ident, $func:ident, $op_trait:ident :: $op_func:ident
+    } => {
+        impl core::ops::$trait<$type> for $type {
+            #[inline]
+            fn $func(&mut self, rhs: Self) {
+                *self = core::ops::$op_trait::$op_func(*self, rhs);
+            }
+        }
+
+        impl core::ops::$trait<<$type as $crate::vector::Vector>::Scalar> for $type {
+            #[inline]
+            fn $func(&mut self, rhs: <$type as $crate::vector::Vector>::Scalar) {
+                *self = core::ops::$op_trait::$op_func(*self, rhs);
+            }
+        }
+    };
+    {
+        @not $type:ty, ()
+    } => {
+        impl core::ops::Not for $type {
+            type Output = Self;
+            #[inline]
+            fn not(mut self) -> Self {
+                use $crate::arch::generic::ScalarBits;
+                for a in self.iter_mut() {
+                    *a = ScalarBits::from_bits(!ScalarBits::to_bits(*a));
+                }
+                self
+            }
+        }
+    };
+    {
+        @not $type:ty, $op:tt
+    } => {
+        impl core::ops::Not for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn not(self) -> Self {
+                Self($crate::bitwise_ops!(@call $op, self.0))
+            }
+        }
+    };
+    {
+        @and_not $type:ty, ()
+    } => {
+        impl $crate::vector::Bitwise for $type {}
+    };
+    {
+        @and_not $type:ty, $op:tt
+    } => {
+        impl $crate::vector::Bitwise for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn and_not(self, rhs: Self) -> Self {
+                Self($crate::bitwise_ops!(@call $op, self.0, rhs.0))
+            }
+        }
+    };
+    {
+        for $type:ty:
+            and -> $and_expr:tt,
+            or -> $or_expr:tt,
+            xor -> $xor_expr:tt,
+            not -> $not_expr:tt,
+            and_not -> $and_not_expr:tt
+    } => {
+        $crate::bitwise_ops!{@new $type, BitAnd, bitand, $and_expr}
+        $crate::bitwise_ops!{@new $type, BitOr, bitor, $or_expr}
+        $crate::bitwise_ops!{@new $type, BitXor, bitxor, $xor_expr}
+        $crate::bitwise_ops!{@assign $type, BitAndAssign, bitand_assign, BitAnd::bitand}
+        $crate::bitwise_ops!{@assign $type, BitOrAssign, bitor_assign, BitOr::bitor}
+        $crate::bitwise_ops!{@assign $type, BitXorAssign, bitxor_assign, BitXor::bitxor}
+        $crate::bitwise_ops!{@not $type, $not_expr}
+        $crate::bitwise_ops!{@and_not $type, $and_not_expr}
+    };
+}
+
+/// Implements the shift operators for a vector of integers.
+///
+/// This implements `Shl<u32>`, `Shr<u32>` and their assigning variants, which shift every lane by
+/// the same number of bits, and therefore [`Shift`].
+/// As with [`arithmetic_ops!`], each operation is `(path)`, `(unsafe path)` or `()`, with the
+/// same safety requirements, but functions take the underlying type and the shift as a `u32`.
+/// Shifts must saturate as described by [`Shift`].
+///
+/// [`Shift`]: crate::vector::Shift
+#[macro_export]
+macro_rules! shift_ops {
+    {
+        @call (unsafe $op:path), $($arg:expr),*
+    } => {
+        unsafe { $op($($arg),*) }
+    };
+    {
+        @call ($op:path), $($arg:expr),*
+    } => {
+        $op($($arg),*)
+    };
+    {
+        @new $type:ty, $trait:ident, $func:ident, ()
+    } => {
+        impl core::ops::$trait<u32> for $type {
+            type Output = Self;
+            #[inline]
+            fn $func(mut self, rhs: u32) -> Self {
+                use $crate::arch::generic::ScalarShift;
+                for a in self.iter_mut() {
+                    *a = ScalarShift::$func(*a, rhs);
+                }
+                self
+            }
+        }
+    };
+    {
+        @new $type:ty, $trait:ident, $func:ident, $op:tt
+    } => {
+        impl core::ops::$trait<u32> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn $func(self, rhs: u32) -> Self {
+                Self($crate::shift_ops!(@call $op, self.0, rhs))
+            }
+        }
+    };
+    {
+        @assign $type:ty, $trait:ident, $func:ident, $op_trait:ident :: $op_func:ident
+    } => {
+        impl core::ops::$trait<u32> for $type {
+            #[inline]
+            fn $func(&mut self, rhs: u32) {
+                *self = core::ops::$op_trait::$op_func(*self, rhs);
+            }
+        }
+    };
+    {
+        for $type:ty:
+            shl -> $shl_expr:tt,
+            shr -> $shr_expr:tt
+    } => {
+        $crate::shift_ops!{@new $type, Shl, shl, $shl_expr}
+        $crate::shift_ops!{@new $type, Shr, shr, $shr_expr}
+        $crate::shift_ops!{@assign $type, ShlAssign, shl_assign, Shl::shl}
+        $crate::shift_ops!{@assign $type, ShrAssign, shr_assign, Shr::shr}
     };
 }
 
+/// Implements slice access for a vector type.
+///
+/// This implements `AsRef`, `AsMut`, `Deref` and `DerefMut` to a slice of scalars, and
+/// conversion to an array of scalars, in terms of [`Vector::as_slice`].
+///
+/// [`Vector::as_slice`]: crate::vector::Vector::as_slice
+#[macro_export]
 macro_rules! as_slice {
     {
         $type:ty
     } => {
-        impl AsRef<[<$type as crate::vector::Vector>::Scalar]> for $type {
+        impl AsRef<[<$type as $crate::vector::Vector>::Scalar]> for $type {
             #[inline]
-            fn as_ref(&self) -> &[<$type as crate::vector::Vector>::Scalar] {
-                use crate::vector::Vector;
+            fn as_ref(&self) -> &[<$type as $crate::vector::Vector>::Scalar] {
+                use $crate::vector::Vector;
                 self.as_slice()
             }
         }
 
-        impl AsMut<[<$type as crate::vector::Vector>::Scalar]> for $type {
+        impl AsMut<[<$type as $crate::vector::Vector>::Scalar]> for $type {
             #[inline]
-            fn as_mut(&mut self) -> &mut [<$type as crate::vector::Vector>::Scalar] {
-                use crate::vector::Vector;
+            fn as_mut(&mut self) -> &mut [<$type as $crate::vector::Vector>::Scalar] {
+                use $crate::vector::Vector;
                 self.as_slice_mut()
             }
         }
 
         impl core::ops::Deref for $type {
-            type Target = [<Self as crate::vector::Vector>::Scalar];
+            type Target = [<Self as $crate::vector::Vector>::Scalar];
             #[inline]
             fn deref(&self) -> &Self::Target {
                 self.as_slice()
@@ -200,5 +467,144 @@ macro_rules! as_slice {
                 self.as_slice_mut()
             }
         }
+
+        impl<const N: usize> From<$type> for [<$type as $crate::vector::Vector>::Scalar; N]
+        where
+            <$type as $crate::vector::Vector>::Width: $crate::vector::width::ArrayOf<
+                <$type as $crate::vector::Vector>::Scalar,
+                Array = [<$type as $crate::vector::Vector>::Scalar; N],
+            >,
+        {
+            #[inline]
+            fn from(from: $type) -> Self {
+                $crate::vector::Vector::to_array(from)
+            }
+        }
     }
 }
+
+/// Implements [`Scalar`] for every width, and [`Native`], for a scalar type and token.
+///
+/// Widths narrower than the base width are listed with their vector types.
+/// Widths from the base width up to 32 lanes use the base vector, multiplied with [`Shim`].
+///
+/// See the [`vector`](crate::vector) module for a complete example.
+///
+/// [`Scalar`]: crate::scalar::Scalar
+/// [`Native`]: crate::vector::Native
+/// [`Shim`]: crate::shim::Shim
+#[macro_export]
+macro_rules! impl_scalar {
+    {
+        @shims $token:ty, $scalar:ty, $vector:ty, 1
+    } => {
+        $crate::impl_scalar! { @shim $token, $scalar, $vector, 1, [2, 4, 8, 16, 32] }
+    };
+    {
+        @shims $token:ty, $scalar:ty, $vector:ty, 2
+    } => {
+        $crate::impl_scalar! { @shim $token, $scalar, $vector, 2, [2, 4, 8, 16] }
+    };
+    {
+        @shims $token:ty, $scalar:ty, $vector:ty, 4
+    } => {
+        $crate::impl_scalar! { @shim $token, $scalar, $vector, 4, [2, 4, 8] }
+    };
+    {
+        @shims $token:ty, $scalar:ty, $vector:ty, 8
+    } => {
+        $crate::impl_scalar! { @shim $token, $scalar, $vector, 8, [2, 4] }
+    };
+    {
+        @shims $token:ty, $scalar:ty, $vector:ty, 16
+    } => {
+        $crate::impl_scalar! { @shim $token, $scalar, $vector, 16, [2] }
+    };
+    {
+        @shims $token:ty, $scalar:ty, $vector:ty, 32
+    } => {
+        $crate::impl_scalar! { @shim $token, $scalar, $vector, 32, [] }
+    };
+    {
+        @shim $token:ty, $scalar:ty, $vector:ty, $base:tt, [$($n:tt),*]
+    } => {
+        impl $crate::scalar::Scalar<$token, $crate::vector::width::Lanes<$base>> for $scalar {
+            type Vector = $vector;
+        }
+
+        $(
+        impl $crate::scalar::Scalar<$token, $crate::vector::width::Lanes<{ $base * $n }>> for $scalar {
+            type Vector = $crate::shim::Shim<$vector, $scalar, $n>;
+        }
+        )*
+    };
+    {
+        $token:ty, $scalar:ty, native $native:tt {
+            $($width:tt => $vector:ty,)*
+        }
+        $base:tt.. => $base_vector:ty
+    } => {
+        impl $crate::vector::Native<$token> for $scalar {
+            type Width = $crate::vector::width::Lanes<$native>;
+        }
+
+        $(
+        impl $crate::scalar::Scalar<$token, $crate::vector::width::Lanes<$width>> for $scalar {
+            type Vector = $vector;
+        }
+        )*
+
+        $crate::impl_scalar! { @shims $token, $scalar, $base_vector, $base }
+    };
+}
+
+macro_rules! convert_lanes {
+    {
+        @lanes $self:ident, $token:ident, $to:ty, $func:path
+    } => {
+        {
+            let mut converted = <$to as crate::vector::Vector>::zeroed($token);
+            for (to, from) in crate::vector::Vector::as_slice_mut(&mut converted)
+                .iter_mut()
+                .zip(crate::vector::Vector::as_slice(&$self))
+            {
+                *to = $func(*from);
+            }
+            converted
+        }
+    };
+    {
+        $from:ty => int $to:ty
+    } => {
+        impl crate::vector::ConvertToInt<$to> for $from {
+            #[inline]
+            fn convert_truncate(self, token: <$to as crate::vector::Vector>::Token) -> $to {
+                use crate::arch::generic::ConvertScalarToInt;
+                convert_lanes! { @lanes self, token, $to, ConvertScalarToInt::convert_truncate }
+            }
+
+            #[inline]
+            fn convert_round(self, token: <$to as crate::vector::Vector>::Token) -> $to {
+                use crate::arch::generic::ConvertScalarToInt;
+                convert_lanes! { @lanes self, token, $to, ConvertScalarToInt::convert_round }
+            }
+
+            #[inline]
+            fn convert_saturate(self, token: <$to as crate::vector::Vector>::Token) -> $to {
+                use crate::arch::generic::ConvertScalarToInt;
+                convert_lanes! { @lanes self, token, $to, ConvertScalarToInt::convert_saturate }
+            }
+        }
+    };
+    {
+        $from:ty => $to:ty
+    } => {
+        impl crate::vector::Convert<$to> for $from {
+            #[inline]
+            fn convert(self, token: <$to as crate::vector::Vector>::Token) -> $to {
+                use crate::arch::generic::ConvertScalar;
+                convert_lanes! { @lanes self, token, $to, ConvertScalar::convert }
+            }
+        }
+    };
+}
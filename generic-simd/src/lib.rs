@@ -1,4 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
 #![cfg_attr(
     all(feature = "nightly", target_arch = "wasm32"),
     feature(wasm_simd, wasm_target_feature)
@@ -11,12 +12,15 @@
 //! cross-platform SIMD operations.
 //!
 //! # Supported architectures
-//! All architectures are supported via scalar fallbacks, but the following instruction sets are
+//! All architectures are supported via portable fallbacks, but the following instruction sets are
 //! also supported:
+//! * SSE2 (x86/x86-64)
 //! * SSE4.1 (x86/x86-64)
 //! * AVX (x86/x86-64)
+//! * x86-64-v2 and x86-64-v3 microarchitecture levels (x86/x86-64)
 //! * NEON (aarch64, with `nightly` cargo feature)
 //! * SIMD128 (wasm32, with `nightly` cargo feature and `simd128` target feature)
+//! * Portable SIMD with `core::simd` (all architectures, with `nightly` cargo feature)
 //!
 //! The various architecture-specific types are available in the [`arch`](arch/index.html) module.
 //!
@@ -117,12 +121,121 @@
 #[doc(hidden)]
 pub use multiversion;
 
+// Feature detection used by `dispatch`, limited by `arch::set_max_token`.
+#[doc(hidden)]
+pub mod detect {
+    pub use crate::are_cpu_features_detected;
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! are_cpu_features_detected {
+    { $($features:tt),* } => {
+        $crate::arch::features_allowed(&[$($features),*])
+            && $crate::multiversion::are_cpu_features_detected!($($features),*)
+    }
+}
+
 /// Multiversions a function over all supported instruction sets.
 ///
 /// Tagging a function with `#[dispatch(token)]` creates a version of the function for each
 /// supported instruction set and provides its token as `token`.
 /// The best supported function variant is selected at runtime.
 ///
+/// # Methods and impl blocks
+/// Methods, including methods with receivers and generic parameters, can be tagged directly
+/// when they are in an inherent impl block.
+/// Tagging an impl block dispatches every method in the block, which also supports trait impls.
+/// Trait methods forward to hidden methods in an inherent impl of the same type, so the type must
+/// be local to the crate.
+/// ```
+/// use generic_simd::{arch::Token, scalar::ScalarExt, slice::SliceExt, vector::NativeVector};
+///
+/// #[inline]
+/// fn sum_impl<T>(token: T, input: &[f32]) -> f32
+/// where
+///     T: Token,
+///     f32: ScalarExt<T> + core::iter::Sum<NativeVector<f32, T>>,
+/// {
+///     let (start, vectors, end) = input.align_native(token);
+///     vectors.iter().copied().sum::<f32>() + start.iter().chain(end).sum::<f32>()
+/// }
+///
+/// trait Kernel {
+///     fn run(&self, input: &[f32]) -> f32;
+/// }
+///
+/// struct Mean;
+///
+/// #[generic_simd::dispatch(token)]
+/// impl Kernel for Mean {
+///     fn run(&self, input: &[f32]) -> f32 {
+///         sum_impl(token, input) / input.len() as f32
+///     }
+/// }
+///
+/// assert_eq!(Mean.run(&[2.; 10]), 2.);
+/// ```
+///
+/// # Selecting tokens
+/// By default, a version is created for each token supported by the target architecture.
+/// The `tokens` option lists the tokens to create versions for, in priority order, and the
+/// `exclude` option removes tokens from the list.
+/// Excluding a token that isn't in the list is an error.
+/// The [`Generic`] token is always used as the fallback.
+/// With the `nightly` feature, the fallback version uses the
+/// [`Portable`](arch::portable::Portable) token instead when it's listed, which it is by default.
+/// A function `foo` then also gets a hidden `foo_portable_version`, which always uses the
+/// portable token, for testing it alongside the `multiversion` clones.
+///
+/// Tokens not known to the macro can be listed with their `multiversion` target.
+/// ```
+/// use generic_simd::dispatch;
+///
+/// // Only create SSE4.1 and generic versions.
+/// #[dispatch(token, tokens = [Sse, Generic])]
+/// pub fn rarely_used(x: &mut [f32]) {
+///     // ...
+/// }
+///
+/// // Create every version except AVX.
+/// #[dispatch(token, exclude = [Avx])]
+/// pub fn no_avx(x: &mut [f32]) {
+///     // ...
+/// }
+///
+/// // Specify the target of a token.
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// #[dispatch(token, tokens = [generic_simd::arch::x86::Avx = "[x86|x86_64]+avx+fma"])]
+/// pub fn avx_with_fma(x: &mut [f32]) {
+///     // ...
+/// }
+/// ```
+///
+/// ```compile_fail
+/// // `Avx2` isn't a token, so it can't be excluded.
+/// #[generic_simd::dispatch(token, exclude = [Avx2])]
+/// pub fn misspelled(x: &mut [f32]) {
+///     // ...
+/// }
+/// ```
+///
+/// # Reporting the selected version
+/// Tagging a function with `#[dispatch(token, report)]` also creates a companion function with
+/// the `_selected_version` suffix, which returns the name of the version selected at runtime,
+/// as in [`AnyToken::name`].
+/// Trait methods are reported by associated functions of the implementing type prefixed with the
+/// snake case trait name, such as `Sum::kernel_run_selected_version` for `Kernel::run`.
+/// ```
+/// #[generic_simd::dispatch(token, report)]
+/// pub fn double(x: f32) -> f32 {
+///     x * 2.
+/// }
+///
+/// let version = double_selected_version();
+/// assert_eq!(version, generic_simd::arch::AnyToken::new().name());
+/// ```
+///
 /// # Implementation
 /// This attribute is a wrapper for [`multiversion`] and supports all of its
 /// conditional compilation and static dispatch features.
@@ -155,6 +268,8 @@ pub use multiversion;
 /// [Abstractions]: index.html#abstractions
 /// [Vector shims]: index.html#vector-shims
 /// [`multiversion`]: ../multiversion/attr.multiversion.html
+/// [`AnyToken::name`]: arch/enum.AnyToken.html#method.name
+/// [`Generic`]: arch/generic/struct.Generic.html
 pub use generic_simd_macros::dispatch;
 
 #[macro_use]
@@ -5,6 +5,22 @@ use crate::{
     vector::{width, Native, NativeWidth, Vector},
 };
 
+/// The expected temporal locality of prefetched data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locality {
+    /// The data will be accessed once and does not need to remain in cache.
+    None,
+
+    /// The data should remain in the outermost levels of cache.
+    Low,
+
+    /// The data should remain in the middle levels of cache.
+    Moderate,
+
+    /// The data should remain in all levels of cache.
+    High,
+}
+
 /// A pointer to a vector.
 pub trait Pointer<Token, Width>: Copy
 where
@@ -24,6 +40,16 @@ where
     /// # Safety
     /// See [`read_aligned_ptr`](../vector/trait.Vector.html#method.read_aligned_ptr).
     unsafe fn vector_read_aligned(self, token: Token) -> Self::Vector;
+
+    /// Prefetch the vector at this pointer for reading.
+    ///
+    /// See [`prefetch_read_ptr`](../vector/trait.Vector.html#method.prefetch_read_ptr).
+    fn vector_prefetch_read(self, token: Token, locality: Locality);
+
+    /// Prefetch the vector at this pointer for writing.
+    ///
+    /// See [`prefetch_write_ptr`](../vector/trait.Vector.html#method.prefetch_write_ptr).
+    fn vector_prefetch_write(self, token: Token, locality: Locality);
 }
 
 impl<T, Token, Width> Pointer<Token, Width> for *const T
@@ -43,6 +69,16 @@ where
     unsafe fn vector_read_aligned(self, token: Token) -> Self::Vector {
         Self::Vector::read_aligned_ptr(token, self)
     }
+
+    #[inline]
+    fn vector_prefetch_read(self, token: Token, locality: Locality) {
+        Self::Vector::prefetch_read_ptr(token, self, locality)
+    }
+
+    #[inline]
+    fn vector_prefetch_write(self, token: Token, locality: Locality) {
+        Self::Vector::prefetch_write_ptr(token, self, locality)
+    }
 }
 
 impl<T, Token, Width> Pointer<Token, Width> for *mut T
@@ -62,29 +98,53 @@ where
     unsafe fn vector_read_aligned(self, token: Token) -> Self::Vector {
         Self::Vector::read_aligned_ptr(token, self)
     }
+
+    #[inline]
+    fn vector_prefetch_read(self, token: Token, locality: Locality) {
+        Self::Vector::prefetch_read_ptr(token, self, locality)
+    }
+
+    #[inline]
+    fn vector_prefetch_write(self, token: Token, locality: Locality) {
+        Self::Vector::prefetch_write_ptr(token, self, locality)
+    }
+}
+
+impl<T, Token> Native<Token> for *const T
+where
+    T: Native<Token>,
+{
+    type Width = T::Width;
+}
+
+impl<T, Token> Native<Token> for *mut T
+where
+    T: Native<Token>,
+{
+    type Width = T::Width;
 }
 
-macro_rules! pointer_impl {
+macro_rules! pointer_lanes_impl {
     {
         $width:literal,
-        $width_type:ty,
-        $read_unaligned:ident,
+        $lanes:literal,
+        $read:ident,
         $read_aligned:ident
     } => {
         #[doc = "Read a vector with "]
         #[doc = $width]
-        #[doc = " from a pointer.\n\n# Safety\nSee [`read_ptr`](../vector/trait.Vector.html#method.read_ptr)."]
+        #[doc = " from a pointer.\n\n# Safety\nSee [`vector_read_lanes`](#method.vector_read_lanes)."]
         #[inline]
-        unsafe fn $read_unaligned(self, token: Token) -> <Self as Pointer<Token, $width_type>>::Vector {
-            <Self as Pointer<Token, $width_type>>::vector_read(self, token)
+        unsafe fn $read(self, token: Token) -> <Self as Pointer<Token, width::Lanes<$lanes>>>::Vector {
+            self.vector_read_lanes::<$lanes>(token)
         }
 
         #[doc = "Read a vector with "]
         #[doc = $width]
-        #[doc = " from a vector-aligned pointer.\n\n# Safety\nSee [`read_aligned_ptr`](../vector/trait.Vector.html#method.read_aligned_ptr)."]
+        #[doc = " from a vector-aligned pointer.\n\n# Safety\nSee [`vector_read_aligned_lanes`](#method.vector_read_aligned_lanes)."]
         #[inline]
-        unsafe fn $read_aligned(self, token: Token) -> <Self as Pointer<Token, $width_type>>::Vector {
-            <Self as Pointer<Token, $width_type>>::vector_read_aligned(self, token)
+        unsafe fn $read_aligned(self, token: Token) -> <Self as Pointer<Token, width::Lanes<$lanes>>>::Vector {
+            self.vector_read_aligned_lanes::<$lanes>(token)
         }
     }
 }
@@ -96,15 +156,92 @@ pub trait PointerExt<Token>:
     + Pointer<Token, width::W2>
     + Pointer<Token, width::W4>
     + Pointer<Token, width::W8>
+    + Pointer<Token, width::W16>
+    + Pointer<Token, width::W32>
     + Pointer<Token, NativeWidth<Self, Token>>
 where
     Token: crate::arch::Token,
 {
-    pointer_impl! { "the native number of lanes", <Self as Native<Token>>::Width, vector_read_native, vector_read_aligned_native }
-    pointer_impl! { "1 lane",  width::W1, vector_read1, vector_read1_aligned }
-    pointer_impl! { "2 lanes", width::W2, vector_read2, vector_read2_aligned }
-    pointer_impl! { "4 lanes", width::W4, vector_read4, vector_read4_aligned }
-    pointer_impl! { "8 lanes", width::W8, vector_read8, vector_read8_aligned }
+    /// Read a vector with the native number of lanes from a pointer.
+    ///
+    /// # Safety
+    /// See [`read_ptr`](../vector/trait.Vector.html#method.read_ptr).
+    #[inline]
+    unsafe fn vector_read_native(
+        self,
+        token: Token,
+    ) -> <Self as Pointer<Token, NativeWidth<Self, Token>>>::Vector {
+        <Self as Pointer<Token, NativeWidth<Self, Token>>>::vector_read(self, token)
+    }
+
+    /// Read a vector with the native number of lanes from a vector-aligned pointer.
+    ///
+    /// # Safety
+    /// See [`read_aligned_ptr`](../vector/trait.Vector.html#method.read_aligned_ptr).
+    #[inline]
+    unsafe fn vector_read_aligned_native(
+        self,
+        token: Token,
+    ) -> <Self as Pointer<Token, NativeWidth<Self, Token>>>::Vector {
+        <Self as Pointer<Token, NativeWidth<Self, Token>>>::vector_read_aligned(self, token)
+    }
+
+    /// Read a vector with `N` lanes from a pointer.
+    ///
+    /// # Safety
+    /// See [`read_ptr`](../vector/trait.Vector.html#method.read_ptr).
+    #[inline]
+    unsafe fn vector_read_lanes<const N: usize>(
+        self,
+        token: Token,
+    ) -> <Self as Pointer<Token, width::Lanes<N>>>::Vector
+    where
+        Self: Pointer<Token, width::Lanes<N>>,
+    {
+        <Self as Pointer<Token, width::Lanes<N>>>::vector_read(self, token)
+    }
+
+    /// Read a vector with `N` lanes from a vector-aligned pointer.
+    ///
+    /// # Safety
+    /// See [`read_aligned_ptr`](../vector/trait.Vector.html#method.read_aligned_ptr).
+    #[inline]
+    unsafe fn vector_read_aligned_lanes<const N: usize>(
+        self,
+        token: Token,
+    ) -> <Self as Pointer<Token, width::Lanes<N>>>::Vector
+    where
+        Self: Pointer<Token, width::Lanes<N>>,
+    {
+        <Self as Pointer<Token, width::Lanes<N>>>::vector_read_aligned(self, token)
+    }
+
+    pointer_lanes_impl! { "1 lane", 1, vector_read1, vector_read1_aligned }
+    pointer_lanes_impl! { "2 lanes", 2, vector_read2, vector_read2_aligned }
+    pointer_lanes_impl! { "4 lanes", 4, vector_read4, vector_read4_aligned }
+    pointer_lanes_impl! { "8 lanes", 8, vector_read8, vector_read8_aligned }
+    pointer_lanes_impl! { "16 lanes", 16, vector_read16, vector_read16_aligned }
+    pointer_lanes_impl! { "32 lanes", 32, vector_read32, vector_read32_aligned }
+
+    /// Prefetch the native vector at this pointer for reading.
+    ///
+    /// See [`prefetch_read_ptr`](../vector/trait.Vector.html#method.prefetch_read_ptr).
+    #[inline]
+    fn prefetch_read(self, token: Token, locality: Locality) {
+        <Self as Pointer<Token, NativeWidth<Self, Token>>>::vector_prefetch_read(
+            self, token, locality,
+        )
+    }
+
+    /// Prefetch the native vector at this pointer for writing.
+    ///
+    /// See [`prefetch_write_ptr`](../vector/trait.Vector.html#method.prefetch_write_ptr).
+    #[inline]
+    fn prefetch_write(self, token: Token, locality: Locality) {
+        <Self as Pointer<Token, NativeWidth<Self, Token>>>::vector_prefetch_write(
+            self, token, locality,
+        )
+    }
 }
 
 impl<T, Token> PointerExt<Token> for T
@@ -114,6 +251,8 @@ where
         + Pointer<Token, width::W2>
         + Pointer<Token, width::W4>
         + Pointer<Token, width::W8>
+        + Pointer<Token, width::W16>
+        + Pointer<Token, width::W32>
         + Pointer<Token, NativeWidth<Self, Token>>,
     Token: crate::arch::Token,
 {
@@ -27,27 +27,27 @@ where
     }
 }
 
-macro_rules! scalar_impl {
+macro_rules! scalar_lanes_impl {
     {
         $width:literal,
-        $width_type:ty,
+        $lanes:literal,
         $zeroed:ident,
         $splat:ident
     } => {
         #[doc = "Create a vector with "]
         #[doc = $width]
-        #[doc = " set to zero.\n\nSee [`zeroed`](../vector/trait.Vector.html#method.zeroed)."]
+        #[doc = " set to zero.\n\nSee [`zeroed_lanes`](#method.zeroed_lanes)."]
         #[inline]
-        fn $zeroed(token: Token) -> <Self as Scalar<Token, $width_type>>::Vector {
-           <Self as Scalar<Token, $width_type>>::zeroed(token.into())
+        fn $zeroed(token: Token) -> <Self as Scalar<Token, width::Lanes<$lanes>>>::Vector {
+            Self::zeroed_lanes::<$lanes>(token)
         }
 
         #[doc = "Splat a scalar to "]
         #[doc = $width]
-        #[doc = ".\n\nSee [`splat`](../vector/trait.Vector.html#tymethod.splat)."]
+        #[doc = ".\n\nSee [`splat_lanes`](#method.splat_lanes)."]
         #[inline]
-        fn $splat(self, token: Token) -> <Self as Scalar<Token, $width_type>>::Vector {
-            <Self as Scalar<Token, $width_type>>::splat(self, token.into())
+        fn $splat(self, token: Token) -> <Self as Scalar<Token, width::Lanes<$lanes>>>::Vector {
+            self.splat_lanes::<$lanes>(token)
         }
     }
 }
@@ -59,15 +59,64 @@ pub trait ScalarExt<Token>:
     + self::Scalar<Token, width::W2>
     + self::Scalar<Token, width::W4>
     + self::Scalar<Token, width::W8>
+    + self::Scalar<Token, width::W16>
+    + self::Scalar<Token, width::W32>
     + self::Scalar<Token, NativeWidth<Self, Token>>
 where
     Token: crate::arch::Token + From<Token> + Into<Token>,
 {
-    scalar_impl! { "the native number of lanes", <Self as Native<Token>>::Width, zeroed_native, splat_native }
-    scalar_impl! { "1 lane",  width::W1, zeroed1, splat1 }
-    scalar_impl! { "2 lanes", width::W2, zeroed2, splat2 }
-    scalar_impl! { "4 lanes", width::W4, zeroed4, splat4 }
-    scalar_impl! { "8 lanes", width::W8, zeroed8, splat8 }
+    /// Create a vector with the native number of lanes set to zero.
+    ///
+    /// See [`zeroed`](../vector/trait.Vector.html#method.zeroed).
+    #[inline]
+    fn zeroed_native(token: Token) -> <Self as Scalar<Token, NativeWidth<Self, Token>>>::Vector {
+        <Self as Scalar<Token, NativeWidth<Self, Token>>>::zeroed(token)
+    }
+
+    /// Splat a scalar to the native number of lanes.
+    ///
+    /// See [`splat`](../vector/trait.Vector.html#tymethod.splat).
+    #[inline]
+    fn splat_native(
+        self,
+        token: Token,
+    ) -> <Self as Scalar<Token, NativeWidth<Self, Token>>>::Vector {
+        <Self as Scalar<Token, NativeWidth<Self, Token>>>::splat(self, token)
+    }
+
+    /// Create a vector with `N` lanes set to zero.
+    ///
+    /// See [`zeroed`](../vector/trait.Vector.html#method.zeroed).
+    #[inline]
+    fn zeroed_lanes<const N: usize>(
+        token: Token,
+    ) -> <Self as Scalar<Token, width::Lanes<N>>>::Vector
+    where
+        Self: Scalar<Token, width::Lanes<N>>,
+    {
+        <Self as Scalar<Token, width::Lanes<N>>>::zeroed(token)
+    }
+
+    /// Splat a scalar to `N` lanes.
+    ///
+    /// See [`splat`](../vector/trait.Vector.html#tymethod.splat).
+    #[inline]
+    fn splat_lanes<const N: usize>(
+        self,
+        token: Token,
+    ) -> <Self as Scalar<Token, width::Lanes<N>>>::Vector
+    where
+        Self: Scalar<Token, width::Lanes<N>>,
+    {
+        <Self as Scalar<Token, width::Lanes<N>>>::splat(self, token)
+    }
+
+    scalar_lanes_impl! { "1 lane", 1, zeroed1, splat1 }
+    scalar_lanes_impl! { "2 lanes", 2, zeroed2, splat2 }
+    scalar_lanes_impl! { "4 lanes", 4, zeroed4, splat4 }
+    scalar_lanes_impl! { "8 lanes", 8, zeroed8, splat8 }
+    scalar_lanes_impl! { "16 lanes", 16, zeroed16, splat16 }
+    scalar_lanes_impl! { "32 lanes", 32, zeroed32, splat32 }
 }
 
 impl<Token, Scalar> ScalarExt<Token> for Scalar
@@ -78,6 +127,121 @@ where
         + self::Scalar<Token, width::W2>
         + self::Scalar<Token, width::W4>
         + self::Scalar<Token, width::W8>
+        + self::Scalar<Token, width::W16>
+        + self::Scalar<Token, width::W32>
         + self::Scalar<Token, NativeWidth<Self, Token>>,
 {
 }
+
+/// A scalar that can be reinterpreted as another scalar.
+///
+/// See [`bitcast`](../vector/trait.Vector.html#method.bitcast).
+///
+/// # Safety
+/// `Self` and `To` must have the same size, and every bit pattern of `Self` must be a valid `To`.
+pub unsafe trait Bitcast<To>: Copy {}
+
+macro_rules! bitcast_impl {
+    { $($scalar:ty),* } => {
+        bitcast_impl! { @from [$($scalar),*] $($scalar),* }
+    };
+    { @from $to:tt $($from:ty),* } => {
+        $(bitcast_impl! { @to $from, $to })*
+    };
+    { @to $from:ty, [$($to:ty),*] } => {
+        $(unsafe impl Bitcast<$to> for $from {})*
+    };
+}
+
+bitcast_impl! { f32, i32, u32 }
+bitcast_impl! { f64, u64 }
+
+/// A scalar that can be created from a lane or element index.
+///
+/// See [`iota`](../vector/trait.Vector.html#method.iota).
+pub trait FromIndex: Copy {
+    /// Converts an index to a scalar, as if by `as`.
+    fn from_index(index: usize) -> Self;
+}
+
+macro_rules! from_index_impl {
+    { $($scalar:ty),* } => {
+        $(
+        impl FromIndex for $scalar {
+            #[inline]
+            fn from_index(index: usize) -> Self {
+                index as $scalar
+            }
+        }
+        )*
+    }
+}
+
+from_index_impl! { f32, f64, i32, u32, u64 }
+
+#[cfg(feature = "complex")]
+impl<T> FromIndex for num_complex::Complex<T>
+where
+    T: FromIndex + Default,
+{
+    #[inline]
+    fn from_index(index: usize) -> Self {
+        Self::new(T::from_index(index), T::default())
+    }
+}
+
+/// A floating-point scalar that can be classified.
+///
+/// See [`Classify`](../vector/trait.Classify.html).
+pub trait Float: Copy + PartialOrd {
+    /// Returns `true` if this value is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Returns `true` if this value is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+
+    /// Returns `true` if this value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// Returns `true` if this value is subnormal.
+    fn is_subnormal(self) -> bool;
+
+    /// Returns `true` if this value has a negative sign bit, including `-0.0` and NaNs with a
+    /// negative sign bit.
+    fn is_sign_negative(self) -> bool;
+}
+
+macro_rules! float_impl {
+    { $($scalar:ty),* } => {
+        $(
+        impl Float for $scalar {
+            #[inline]
+            fn is_nan(self) -> bool {
+                <$scalar>::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                <$scalar>::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                <$scalar>::is_finite(self)
+            }
+
+            #[inline]
+            fn is_subnormal(self) -> bool {
+                <$scalar>::classify(self) == core::num::FpCategory::Subnormal
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                <$scalar>::is_sign_negative(self)
+            }
+        }
+        )*
+    }
+}
+
+float_impl! { f32, f64 }
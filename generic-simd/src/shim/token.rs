@@ -1,5 +1,10 @@
 use crate::arch;
-use crate::vector::Vector;
+use crate::arch::generic::ScalarArithmetic;
+use crate::pointer::Locality;
+use crate::scalar::Float;
+use crate::vector::{
+    width, Bitwise, Classify, Convert, ConvertToInt, Horizontal, LaneMask, PrefixSum, Vector,
+};
 use core::marker::PhantomData;
 
 #[cfg(feature = "complex")]
@@ -8,7 +13,10 @@ use crate::vector::Complex;
 /// Shim that converts the associated token.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
-pub struct ShimToken<Underlying, Scalar, Token>(Underlying, PhantomData<(Scalar, Token)>);
+pub struct ShimToken<Underlying, Scalar, Token>(
+    pub(crate) Underlying,
+    pub(crate) PhantomData<(Scalar, Token)>,
+);
 
 unsafe impl<Underlying, Scalar, Token> Vector for ShimToken<Underlying, Scalar, Token>
 where
@@ -30,6 +38,26 @@ where
     fn splat(token: Self::Token, from: Self::Scalar) -> Self {
         Self(Underlying::splat(token.into(), from), PhantomData)
     }
+
+    #[inline]
+    unsafe fn write_stream_aligned_ptr(self, to: *mut Self::Scalar) {
+        self.0.write_stream_aligned_ptr(to);
+    }
+
+    #[inline]
+    fn stream_fence(token: Self::Token) {
+        Underlying::stream_fence(token.into())
+    }
+
+    #[inline]
+    fn prefetch_read_ptr(token: Self::Token, from: *const Self::Scalar, locality: Locality) {
+        Underlying::prefetch_read_ptr(token.into(), from, locality)
+    }
+
+    #[inline]
+    fn prefetch_write_ptr(token: Self::Token, to: *const Self::Scalar, locality: Locality) {
+        Underlying::prefetch_write_ptr(token.into(), to, locality)
+    }
 }
 
 impl<Underlying, Scalar, Token> AsRef<[Scalar]> for ShimToken<Underlying, Scalar, Token>
@@ -74,6 +102,34 @@ where
     }
 }
 
+impl<Underlying, Scalar, Token, const N: usize> From<ShimToken<Underlying, Scalar, Token>>
+    for [Scalar; N]
+where
+    Underlying: Vector<Scalar = Scalar>,
+    Underlying::Width: width::ArrayOf<Scalar, Array = [Scalar; N]>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn from(from: ShimToken<Underlying, Scalar, Token>) -> Self {
+        from.to_array()
+    }
+}
+
+impl<Underlying, Scalar, const N: usize> From<[Scalar; N]>
+    for ShimToken<Underlying, Scalar, arch::generic::Generic>
+where
+    Underlying: Vector<Scalar = Scalar>,
+    Underlying::Width: width::ArrayOf<Scalar, Array = [Scalar; N]>,
+    Scalar: Copy,
+    arch::generic::Generic: Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn from(from: [Scalar; N]) -> Self {
+        Self::from_array(arch::generic::Generic, from)
+    }
+}
+
 macro_rules! implement {
     {
         @op $trait:ident :: $func:ident
@@ -129,6 +185,32 @@ macro_rules! implement {
             }
         }
     };
+
+    {
+        @shift $trait:ident :: $func:ident, $assign_trait:ident :: $assign_func:ident
+    } => {
+        impl<Underlying, Scalar, Token> core::ops::$trait<u32> for ShimToken<Underlying, Scalar, Token>
+        where
+            Underlying: Copy + core::ops::$trait<u32, Output=Underlying>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $func(self, rhs: u32) -> Self {
+                Self((self.0).$func(rhs), PhantomData)
+            }
+        }
+
+        impl<Underlying, Scalar, Token> core::ops::$assign_trait<u32> for ShimToken<Underlying, Scalar, Token>
+        where
+            Underlying: Copy + core::ops::$assign_trait<u32>,
+        {
+            #[inline]
+            fn $assign_func(&mut self, rhs: u32) {
+                (self.0).$assign_func(rhs);
+            }
+        }
+    };
 }
 
 implement! { @op Add::add }
@@ -139,6 +221,14 @@ implement! { @op_assign AddAssign::add_assign }
 implement! { @op_assign SubAssign::sub_assign }
 implement! { @op_assign MulAssign::mul_assign }
 implement! { @op_assign DivAssign::div_assign }
+implement! { @op BitAnd::bitand }
+implement! { @op BitOr::bitor }
+implement! { @op BitXor::bitxor }
+implement! { @op_assign BitAndAssign::bitand_assign }
+implement! { @op_assign BitOrAssign::bitor_assign }
+implement! { @op_assign BitXorAssign::bitxor_assign }
+implement! { @shift Shl::shl, ShlAssign::shl_assign }
+implement! { @shift Shr::shr, ShrAssign::shr_assign }
 
 impl<Underlying, Scalar, Token> core::ops::Neg for ShimToken<Underlying, Scalar, Token>
 where
@@ -152,6 +242,102 @@ where
     }
 }
 
+impl<Underlying, Scalar, Token> core::ops::Not for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Copy + core::ops::Not<Output = Underlying>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0, PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Bitwise for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Vector<Scalar = Scalar> + Bitwise,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn and_not(self, rhs: Self) -> Self {
+        Self(self.0.and_not(rhs.0), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> PrefixSum for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Vector<Scalar = Scalar> + PrefixSum,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn prefix_sum(self) -> Self {
+        Self(self.0.prefix_sum(), PhantomData)
+    }
+
+    #[inline]
+    fn exclusive_prefix_sum(self) -> Self {
+        Self(self.0.exclusive_prefix_sum(), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Horizontal for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Vector<Scalar = Scalar> + Horizontal,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn horizontal_add_pairs(self, rhs: Self) -> Self {
+        Self(self.0.horizontal_add_pairs(rhs.0), PhantomData)
+    }
+
+    #[inline]
+    fn horizontal_sum(self) -> Scalar {
+        self.0.horizontal_sum()
+    }
+
+    #[inline]
+    fn dot(self, rhs: Self) -> Scalar {
+        self.0.dot(rhs.0)
+    }
+}
+
+impl<Underlying, Scalar, Token> Classify for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Vector<Scalar = Scalar> + Classify,
+    Underlying::Width: width::ArrayOf<bool>,
+    Scalar: Float,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn is_nan(self) -> LaneMask<Self> {
+        self.0.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> LaneMask<Self> {
+        self.0.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> LaneMask<Self> {
+        self.0.is_finite()
+    }
+
+    #[inline]
+    fn is_subnormal(self) -> LaneMask<Self> {
+        self.0.is_subnormal()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> LaneMask<Self> {
+        self.0.is_sign_negative()
+    }
+}
+
 impl<Underlying, Scalar, Token> core::iter::Sum<ShimToken<Underlying, Scalar, Token>>
     for Option<ShimToken<Underlying, Scalar, Token>>
 where
@@ -180,7 +366,7 @@ where
     Option<ShimToken<Underlying, Scalar, Token>>:
         core::iter::Sum<ShimToken<Underlying, Scalar, Token>>,
     Underlying: Vector<Scalar = Scalar>,
-    Scalar: Copy + core::ops::Add<Self, Output = Self> + Default,
+    Scalar: ScalarArithmetic + Default,
     Token: arch::Token,
     Underlying::Token: From<Token>,
 {
@@ -189,13 +375,15 @@ where
     where
         I: Iterator<Item = ShimToken<Underlying, Scalar, Token>>,
     {
-        let mut value = Self::default();
         if let Some(sums) = iter.sum::<Option<ShimToken<Underlying, Scalar, Token>>>() {
-            for sum in sums.as_slice() {
-                value = value + *sum;
-            }
+            sums.as_slice()
+                .iter()
+                .copied()
+                .reduce(ScalarArithmetic::add)
+                .unwrap_or_default()
+        } else {
+            Self::default()
         }
-        value
     }
 }
 
@@ -227,7 +415,7 @@ where
     Option<ShimToken<Underlying, Scalar, Token>>:
         core::iter::Product<ShimToken<Underlying, Scalar, Token>>,
     Underlying: Vector<Scalar = Scalar>,
-    Scalar: Copy + core::ops::Mul<Self, Output = Self> + Default,
+    Scalar: ScalarArithmetic + Default,
     Token: arch::Token,
     Underlying::Token: From<Token>,
 {
@@ -236,13 +424,58 @@ where
     where
         I: Iterator<Item = ShimToken<Underlying, Scalar, Token>>,
     {
-        let mut value = Self::default();
         if let Some(products) = iter.product::<Option<ShimToken<Underlying, Scalar, Token>>>() {
-            for product in products.as_slice() {
-                value = value * *product;
-            }
+            products
+                .as_slice()
+                .iter()
+                .copied()
+                .reduce(ScalarArithmetic::mul)
+                .unwrap_or_default()
+        } else {
+            Self::default()
         }
-        value
+    }
+}
+
+impl<Underlying, Scalar, ToUnderlying, ToScalar, Token>
+    Convert<ShimToken<ToUnderlying, ToScalar, Token>> for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Convert<ToUnderlying> + Vector<Scalar = Scalar>,
+    ToUnderlying: Vector<Scalar = ToScalar>,
+    Scalar: Copy,
+    ToScalar: Copy,
+    Token:
+        arch::Token + Into<<Underlying as Vector>::Token> + Into<<ToUnderlying as Vector>::Token>,
+{
+    #[inline]
+    fn convert(self, token: Token) -> ShimToken<ToUnderlying, ToScalar, Token> {
+        ShimToken(self.0.convert(token.into()), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, ToUnderlying, ToScalar, Token>
+    ConvertToInt<ShimToken<ToUnderlying, ToScalar, Token>> for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: ConvertToInt<ToUnderlying> + Vector<Scalar = Scalar>,
+    ToUnderlying: Vector<Scalar = ToScalar>,
+    Scalar: Copy,
+    ToScalar: Copy,
+    Token:
+        arch::Token + Into<<Underlying as Vector>::Token> + Into<<ToUnderlying as Vector>::Token>,
+{
+    #[inline]
+    fn convert_truncate(self, token: Token) -> ShimToken<ToUnderlying, ToScalar, Token> {
+        ShimToken(self.0.convert_truncate(token.into()), PhantomData)
+    }
+
+    #[inline]
+    fn convert_round(self, token: Token) -> ShimToken<ToUnderlying, ToScalar, Token> {
+        ShimToken(self.0.convert_round(token.into()), PhantomData)
+    }
+
+    #[inline]
+    fn convert_saturate(self, token: Token) -> ShimToken<ToUnderlying, ToScalar, Token> {
+        ShimToken(self.0.convert_saturate(token.into()), PhantomData)
     }
 }
 
@@ -1,63 +1,112 @@
-use crate::vector::{width, Vector};
+use crate::arch::generic::{Generic, ScalarArithmetic};
+use crate::pointer::Locality;
+use crate::scalar::Float;
+use crate::vector::{
+    lane_mask_from_fn, width, width::Multiply, Bitwise, Classify, Convert, ConvertToInt,
+    Horizontal, LaneMask, PrefixSum, Vector,
+};
 use core::marker::PhantomData;
 
 #[cfg(feature = "complex")]
 use crate::vector::Complex;
 
-/// Determines the doubled width of this vector.
-pub trait Double {
-    type Doubled: width::Width;
-}
-
-impl Double for width::W1 {
-    type Doubled = width::W2;
-}
-
-impl Double for width::W2 {
-    type Doubled = width::W4;
-}
-
-impl Double for width::W4 {
-    type Doubled = width::W8;
-}
-
-/// Shim that doubles the width of a vector.
+/// Shim that multiplies the width of a vector by `N`.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
-pub struct Shim2<Underlying, Scalar>([Underlying; 2], PhantomData<Scalar>);
+pub struct Shim<Underlying, Scalar, const N: usize>(
+    pub(crate) [Underlying; N],
+    pub(crate) PhantomData<Scalar>,
+);
 
-/// Shim that quadruples the width of a vector.
-pub type Shim4<Underlying, Scalar> = Shim2<Shim2<Underlying, Scalar>, Scalar>;
+impl<Underlying, Scalar, const N: usize> Shim<Underlying, Scalar, N>
+where
+    Underlying: Copy,
+{
+    // Applies `f` to each underlying vector.
+    #[inline]
+    fn map<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(Underlying) -> Underlying,
+    {
+        for v in self.0.iter_mut() {
+            *v = f(*v);
+        }
+        self
+    }
 
-/// Shim that octuples the width of a vector.
-pub type Shim8<Underlying, Scalar> = Shim4<Shim2<Underlying, Scalar>, Scalar>;
+    // Applies `f` to each pair of underlying vectors.
+    #[inline]
+    fn zip_map<F>(mut self, rhs: Self, mut f: F) -> Self
+    where
+        F: FnMut(Underlying, Underlying) -> Underlying,
+    {
+        for (v, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *v = f(*v, *rhs);
+        }
+        self
+    }
+}
 
-unsafe impl<Underlying, Scalar> Vector for Shim2<Underlying, Scalar>
+unsafe impl<Underlying, Scalar, const N: usize> Vector for Shim<Underlying, Scalar, N>
 where
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
+    Underlying::Width: Multiply<N>,
     Scalar: Copy,
 {
     type Scalar = Scalar;
     type Token = <Underlying as Vector>::Token;
-    type Width = <Underlying::Width as Double>::Doubled;
-    type Underlying = [<Underlying as Vector>::Underlying; 2];
+    type Width = <Underlying::Width as Multiply<N>>::Product;
+    type Underlying = [<Underlying as Vector>::Underlying; N];
 
     #[inline]
     fn zeroed(token: Self::Token) -> Self {
-        Self([Underlying::zeroed(token); 2], PhantomData)
+        Self([Underlying::zeroed(token); N], PhantomData)
     }
 
     #[inline]
     fn splat(token: Self::Token, from: Self::Scalar) -> Self {
-        Self([Underlying::splat(token, from); 2], PhantomData)
+        Self([Underlying::splat(token, from); N], PhantomData)
+    }
+
+    #[inline]
+    unsafe fn write_stream_aligned_ptr(self, to: *mut Self::Scalar) {
+        for (i, v) in self.0.iter().enumerate() {
+            v.write_stream_aligned_ptr(to.add(i * Underlying::width()));
+        }
+    }
+
+    #[inline]
+    fn stream_fence(token: Self::Token) {
+        Underlying::stream_fence(token)
+    }
+
+    #[inline]
+    fn prefetch_read_ptr(token: Self::Token, from: *const Self::Scalar, locality: Locality) {
+        for i in 0..N {
+            Underlying::prefetch_read_ptr(
+                token,
+                from.wrapping_add(i * Underlying::width()),
+                locality,
+            );
+        }
+    }
+
+    #[inline]
+    fn prefetch_write_ptr(token: Self::Token, to: *const Self::Scalar, locality: Locality) {
+        for i in 0..N {
+            Underlying::prefetch_write_ptr(
+                token,
+                to.wrapping_add(i * Underlying::width()),
+                locality,
+            );
+        }
     }
 }
 
-impl<Underlying, Scalar> AsRef<[Scalar]> for Shim2<Underlying, Scalar>
+impl<Underlying, Scalar, const N: usize> AsRef<[Scalar]> for Shim<Underlying, Scalar, N>
 where
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
+    Underlying::Width: Multiply<N>,
     Scalar: Copy,
 {
     #[inline]
@@ -66,10 +115,10 @@ where
     }
 }
 
-impl<Underlying, Scalar> AsMut<[Scalar]> for Shim2<Underlying, Scalar>
+impl<Underlying, Scalar, const N: usize> AsMut<[Scalar]> for Shim<Underlying, Scalar, N>
 where
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
+    Underlying::Width: Multiply<N>,
     Scalar: Copy,
 {
     #[inline]
@@ -78,10 +127,10 @@ where
     }
 }
 
-impl<Underlying, Scalar> core::ops::Deref for Shim2<Underlying, Scalar>
+impl<Underlying, Scalar, const N: usize> core::ops::Deref for Shim<Underlying, Scalar, N>
 where
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
+    Underlying::Width: Multiply<N>,
     Scalar: Copy,
 {
     type Target = [Scalar];
@@ -92,10 +141,10 @@ where
     }
 }
 
-impl<Underlying, Scalar> core::ops::DerefMut for Shim2<Underlying, Scalar>
+impl<Underlying, Scalar, const N: usize> core::ops::DerefMut for Shim<Underlying, Scalar, N>
 where
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
+    Underlying::Width: Multiply<N>,
     Scalar: Copy,
 {
     #[inline]
@@ -104,11 +153,39 @@ where
     }
 }
 
+impl<Underlying, Scalar, const N: usize, const M: usize> From<Shim<Underlying, Scalar, N>>
+    for [Scalar; M]
+where
+    Underlying: Vector<Scalar = Scalar>,
+    Underlying::Width: Multiply<N>,
+    <Underlying::Width as Multiply<N>>::Product: width::ArrayOf<Scalar, Array = [Scalar; M]>,
+    Scalar: Copy,
+{
+    #[inline]
+    fn from(from: Shim<Underlying, Scalar, N>) -> Self {
+        from.to_array()
+    }
+}
+
+impl<Underlying, Scalar, const N: usize, const M: usize> From<[Scalar; M]>
+    for Shim<Underlying, Scalar, N>
+where
+    Underlying: Vector<Scalar = Scalar, Token = Generic>,
+    Underlying::Width: Multiply<N>,
+    <Underlying::Width as Multiply<N>>::Product: width::ArrayOf<Scalar, Array = [Scalar; M]>,
+    Scalar: Copy,
+{
+    #[inline]
+    fn from(from: [Scalar; M]) -> Self {
+        Self::from_array(Generic, from)
+    }
+}
+
 macro_rules! implement {
     {
         @op $trait:ident :: $func:ident
     } => {
-        impl<Underlying, Scalar> core::ops::$trait<Self> for Shim2<Underlying, Scalar>
+        impl<Underlying, Scalar, const N: usize> core::ops::$trait<Self> for Shim<Underlying, Scalar, N>
         where
             Underlying: Copy + core::ops::$trait<Underlying, Output=Underlying>,
         {
@@ -116,11 +193,11 @@ macro_rules! implement {
 
             #[inline]
             fn $func(self, rhs: Self) -> Self {
-                Self([self.0[0].$func(rhs.0[0]), self.0[1].$func(rhs.0[1])], PhantomData)
+                self.zip_map(rhs, |v, rhs| v.$func(rhs))
             }
         }
 
-        impl<Underlying, Scalar> core::ops::$trait<Scalar> for Shim2<Underlying, Scalar>
+        impl<Underlying, Scalar, const N: usize> core::ops::$trait<Scalar> for Shim<Underlying, Scalar, N>
         where
             Underlying: Copy + core::ops::$trait<Scalar, Output=Underlying>,
             Scalar: Copy,
@@ -129,7 +206,7 @@ macro_rules! implement {
 
             #[inline]
             fn $func(self, rhs: Scalar) -> Self {
-                Self([self.0[0].$func(rhs), self.0[1].$func(rhs)], PhantomData)
+                self.map(|v| v.$func(rhs))
             }
         }
     };
@@ -137,27 +214,57 @@ macro_rules! implement {
     {
         @op_assign $trait:ident :: $func:ident
     } => {
-        impl<Underlying, Scalar> core::ops::$trait<Self> for Shim2<Underlying, Scalar>
+        impl<Underlying, Scalar, const N: usize> core::ops::$trait<Self> for Shim<Underlying, Scalar, N>
         where
             Underlying: Copy + core::ops::$trait<Underlying>,
             Scalar: Copy,
         {
             #[inline]
             fn $func(&mut self, rhs: Self) {
-                self.0[0].$func(rhs.0[0]);
-                self.0[1].$func(rhs.0[1]);
+                for (v, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    v.$func(*rhs);
+                }
             }
         }
 
-        impl<Underlying, Scalar> core::ops::$trait<Scalar> for Shim2<Underlying, Scalar>
+        impl<Underlying, Scalar, const N: usize> core::ops::$trait<Scalar> for Shim<Underlying, Scalar, N>
         where
             Underlying: Copy + core::ops::$trait<Scalar>,
             Scalar: Copy,
         {
             #[inline]
             fn $func(&mut self, rhs: Scalar) {
-                self.0[0].$func(rhs);
-                self.0[1].$func(rhs);
+                for v in self.0.iter_mut() {
+                    v.$func(rhs);
+                }
+            }
+        }
+    };
+
+    {
+        @shift $trait:ident :: $func:ident, $assign_trait:ident :: $assign_func:ident
+    } => {
+        impl<Underlying, Scalar, const N: usize> core::ops::$trait<u32> for Shim<Underlying, Scalar, N>
+        where
+            Underlying: Copy + core::ops::$trait<u32, Output=Underlying>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $func(self, rhs: u32) -> Self {
+                self.map(|v| v.$func(rhs))
+            }
+        }
+
+        impl<Underlying, Scalar, const N: usize> core::ops::$assign_trait<u32> for Shim<Underlying, Scalar, N>
+        where
+            Underlying: Copy + core::ops::$assign_trait<u32>,
+        {
+            #[inline]
+            fn $assign_func(&mut self, rhs: u32) {
+                for v in self.0.iter_mut() {
+                    v.$assign_func(rhs);
+                }
             }
         }
     };
@@ -171,8 +278,16 @@ implement! { @op_assign AddAssign::add_assign }
 implement! { @op_assign SubAssign::sub_assign }
 implement! { @op_assign MulAssign::mul_assign }
 implement! { @op_assign DivAssign::div_assign }
-
-impl<Underlying, Scalar> core::ops::Neg for Shim2<Underlying, Scalar>
+implement! { @op BitAnd::bitand }
+implement! { @op BitOr::bitor }
+implement! { @op BitXor::bitxor }
+implement! { @op_assign BitAndAssign::bitand_assign }
+implement! { @op_assign BitOrAssign::bitor_assign }
+implement! { @op_assign BitXorAssign::bitxor_assign }
+implement! { @shift Shl::shl, ShlAssign::shl_assign }
+implement! { @shift Shr::shr, ShrAssign::shr_assign }
+
+impl<Underlying, Scalar, const N: usize> core::ops::Neg for Shim<Underlying, Scalar, N>
 where
     Underlying: Copy + core::ops::Neg<Output = Underlying>,
 {
@@ -180,20 +295,134 @@ where
 
     #[inline]
     fn neg(self) -> Self {
-        Self([-self.0[0], -self.0[1]], PhantomData)
+        self.map(|v| -v)
+    }
+}
+
+impl<Underlying, Scalar, const N: usize> core::ops::Not for Shim<Underlying, Scalar, N>
+where
+    Underlying: Copy + core::ops::Not<Output = Underlying>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.map(|v| !v)
     }
 }
 
-impl<Underlying, Scalar> core::iter::Sum<Shim2<Underlying, Scalar>>
-    for Option<Shim2<Underlying, Scalar>>
+impl<Underlying, Scalar, const N: usize> Bitwise for Shim<Underlying, Scalar, N>
 where
-    Shim2<Underlying, Scalar>: core::ops::AddAssign,
+    Underlying: Vector<Scalar = Scalar> + Bitwise,
+    Underlying::Width: Multiply<N>,
+    Scalar: Copy,
+{
+    #[inline]
+    fn and_not(self, rhs: Self) -> Self {
+        self.zip_map(rhs, Underlying::and_not)
+    }
+}
+
+impl<Underlying, Scalar, const N: usize> PrefixSum for Shim<Underlying, Scalar, N>
+where
+    Underlying: Vector<Scalar = Scalar> + PrefixSum,
+    Underlying::Width: Multiply<N>,
+    Scalar: Copy,
+{
+    #[inline]
+    fn prefix_sum(self) -> Self {
+        let mut carry = None;
+        self.map(|v| {
+            let mut scanned = v.prefix_sum();
+            if let Some(carry) = carry {
+                scanned += carry;
+            }
+            carry = Some(scanned[Underlying::width() - 1]);
+            scanned
+        })
+    }
+
+    #[inline]
+    fn exclusive_prefix_sum(self) -> Self {
+        let mut carry = None;
+        self.map(|v| {
+            let mut scanned = v.exclusive_prefix_sum();
+            if let Some(carry) = carry {
+                scanned += carry;
+            }
+            carry = Some((scanned + v)[Underlying::width() - 1]);
+            scanned
+        })
+    }
+}
+
+impl<Underlying, Scalar, const N: usize> Horizontal for Shim<Underlying, Scalar, N>
+where
+    Underlying: Vector<Scalar = Scalar> + Horizontal,
+    Underlying::Width: Multiply<N>,
+    Scalar: Copy,
+{
+    #[inline]
+    fn horizontal_add_pairs(self, rhs: Self) -> Self {
+        // Pairs never straddle two underlying vectors, so add pairs of underlying vectors.
+        let vectors = |i: usize| if i < N { self.0[i] } else { rhs.0[i - N] };
+        let mut added = self;
+        for (i, v) in added.0.iter_mut().enumerate() {
+            *v = vectors(2 * i).horizontal_add_pairs(vectors(2 * i + 1));
+        }
+        added
+    }
+
+    #[inline]
+    fn horizontal_sum(self) -> Scalar {
+        let mut sum = self.0[0];
+        for v in &self.0[1..] {
+            sum += *v;
+        }
+        sum.horizontal_sum()
+    }
+
+    #[inline]
+    fn dot(self, rhs: Self) -> Scalar {
+        (self * rhs).horizontal_sum()
+    }
+}
+
+macro_rules! classify_parts {
+    { $($predicate:ident),* } => {
+        $(
+        #[inline]
+        fn $predicate(self) -> LaneMask<Self> {
+            let mut mask = lane_mask_from_fn::<Self, _>(|_| false);
+            for (lanes, v) in mask.as_mut().chunks_mut(Underlying::width()).zip(self.0.iter()) {
+                lanes.copy_from_slice(v.$predicate().as_ref());
+            }
+            mask
+        }
+        )*
+    }
+}
+
+impl<Underlying, Scalar, const N: usize> Classify for Shim<Underlying, Scalar, N>
+where
+    Underlying: Vector<Scalar = Scalar> + Classify,
+    Underlying::Width: Multiply<N> + width::ArrayOf<bool>,
+    <Underlying::Width as Multiply<N>>::Product: width::ArrayOf<bool>,
+    Scalar: Float,
+{
+    classify_parts! { is_nan, is_infinite, is_finite, is_subnormal, is_sign_negative }
+}
+
+impl<Underlying, Scalar, const N: usize> core::iter::Sum<Shim<Underlying, Scalar, N>>
+    for Option<Shim<Underlying, Scalar, N>>
+where
+    Shim<Underlying, Scalar, N>: core::ops::AddAssign,
     Underlying: Copy,
 {
     #[inline]
     fn sum<I>(mut iter: I) -> Self
     where
-        I: Iterator<Item = Shim2<Underlying, Scalar>>,
+        I: Iterator<Item = Shim<Underlying, Scalar, N>>,
     {
         if let Some(mut sum) = iter.next() {
             for v in iter {
@@ -206,39 +435,41 @@ where
     }
 }
 
-impl<Underlying, Scalar> core::iter::Sum<Shim2<Underlying, Scalar>>
-    for <Shim2<Underlying, Scalar> as Vector>::Scalar
+impl<Underlying, Scalar, const N: usize> core::iter::Sum<Shim<Underlying, Scalar, N>>
+    for <Shim<Underlying, Scalar, N> as Vector>::Scalar
 where
-    Option<Shim2<Underlying, Scalar>>: core::iter::Sum<Shim2<Underlying, Scalar>>,
+    Option<Shim<Underlying, Scalar, N>>: core::iter::Sum<Shim<Underlying, Scalar, N>>,
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
-    Scalar: Copy + core::ops::Add<Self, Output = Self> + Default,
+    Underlying::Width: Multiply<N>,
+    Scalar: ScalarArithmetic + Default,
 {
     #[inline]
     fn sum<I>(iter: I) -> Self
     where
-        I: Iterator<Item = Shim2<Underlying, Scalar>>,
+        I: Iterator<Item = Shim<Underlying, Scalar, N>>,
     {
-        let mut value = Self::default();
-        if let Some(sums) = iter.sum::<Option<Shim2<Underlying, Scalar>>>() {
-            for sum in sums.as_slice() {
-                value = value + *sum;
-            }
+        if let Some(sums) = iter.sum::<Option<Shim<Underlying, Scalar, N>>>() {
+            sums.as_slice()
+                .iter()
+                .copied()
+                .reduce(ScalarArithmetic::add)
+                .unwrap_or_default()
+        } else {
+            Self::default()
         }
-        value
     }
 }
 
-impl<Underlying, Scalar> core::iter::Product<Shim2<Underlying, Scalar>>
-    for Option<Shim2<Underlying, Scalar>>
+impl<Underlying, Scalar, const N: usize> core::iter::Product<Shim<Underlying, Scalar, N>>
+    for Option<Shim<Underlying, Scalar, N>>
 where
-    Shim2<Underlying, Scalar>: core::ops::MulAssign,
+    Shim<Underlying, Scalar, N>: core::ops::MulAssign,
     Underlying: Copy,
 {
     #[inline]
     fn product<I>(mut iter: I) -> Self
     where
-        I: Iterator<Item = Shim2<Underlying, Scalar>>,
+        I: Iterator<Item = Shim<Underlying, Scalar, N>>,
     {
         if let Some(mut sum) = iter.next() {
             for v in iter {
@@ -251,50 +482,181 @@ where
     }
 }
 
-impl<Underlying, Scalar> core::iter::Product<Shim2<Underlying, Scalar>>
-    for <Shim2<Underlying, Scalar> as Vector>::Scalar
+impl<Underlying, Scalar, const N: usize> core::iter::Product<Shim<Underlying, Scalar, N>>
+    for <Shim<Underlying, Scalar, N> as Vector>::Scalar
 where
-    Option<Shim2<Underlying, Scalar>>: core::iter::Product<Shim2<Underlying, Scalar>>,
+    Option<Shim<Underlying, Scalar, N>>: core::iter::Product<Shim<Underlying, Scalar, N>>,
     Underlying: Vector<Scalar = Scalar>,
-    Underlying::Width: Double,
-    Scalar: Copy + core::ops::Mul<Self, Output = Self> + Default,
+    Underlying::Width: Multiply<N>,
+    Scalar: ScalarArithmetic + Default,
 {
     #[inline]
     fn product<I>(iter: I) -> Self
     where
-        I: Iterator<Item = Shim2<Underlying, Scalar>>,
+        I: Iterator<Item = Shim<Underlying, Scalar, N>>,
     {
-        let mut value = Self::default();
-        if let Some(products) = iter.product::<Option<Shim2<Underlying, Scalar>>>() {
-            for product in products.as_slice() {
-                value = value * *product;
+        if let Some(products) = iter.product::<Option<Shim<Underlying, Scalar, N>>>() {
+            products
+                .as_slice()
+                .iter()
+                .copied()
+                .reduce(ScalarArithmetic::mul)
+                .unwrap_or_default()
+        } else {
+            Self::default()
+        }
+    }
+}
+
+// Converts each underlying vector.
+macro_rules! convert_parts {
+    { $($convert:ident),* } => {
+        $(
+        #[inline]
+        fn $convert(self, token: ToUnderlying::Token) -> Shim<ToUnderlying, ToScalar, N> {
+            let mut converted = [ToUnderlying::zeroed(token); N];
+            for (to, from) in converted.iter_mut().zip(self.0.iter()) {
+                *to = from.$convert(token);
             }
+            Shim(converted, PhantomData)
         }
-        value
+        )*
     }
 }
 
+impl<Underlying, Scalar, ToUnderlying, ToScalar, const N: usize>
+    Convert<Shim<ToUnderlying, ToScalar, N>> for Shim<Underlying, Scalar, N>
+where
+    Underlying: Convert<ToUnderlying> + Vector<Scalar = Scalar>,
+    Underlying::Width: Multiply<N>,
+    ToUnderlying: Vector<Scalar = ToScalar>,
+    ToUnderlying::Width: Multiply<N>,
+    Scalar: Copy,
+    ToScalar: Copy,
+{
+    convert_parts! { convert }
+}
+
+impl<Underlying, Scalar, ToUnderlying, ToScalar, const N: usize>
+    ConvertToInt<Shim<ToUnderlying, ToScalar, N>> for Shim<Underlying, Scalar, N>
+where
+    Underlying: ConvertToInt<ToUnderlying> + Vector<Scalar = Scalar>,
+    Underlying::Width: Multiply<N>,
+    ToUnderlying: Vector<Scalar = ToScalar>,
+    ToUnderlying::Width: Multiply<N>,
+    Scalar: Copy,
+    ToScalar: Copy,
+{
+    convert_parts! { convert_truncate, convert_round, convert_saturate }
+}
+
+// Converts shims with fewer, wider underlying vectors to shims with more, narrower underlying
+// vectors, and back, by converting between one vector and a shim of `$ratio` vectors.
+// The counts are listed, since `Shim<_, _, { N * RATIO }>` requires `generic_const_exprs`.
+macro_rules! convert_ratio {
+    {
+        @widen $n:literal * $ratio:literal = $m:literal, $trait:ident, $($convert:ident),*
+    } => {
+        impl<Underlying, Scalar, ToUnderlying, ToScalar>
+            $trait<Shim<ToUnderlying, ToScalar, $m>> for Shim<Underlying, Scalar, $n>
+        where
+            Underlying: $trait<Shim<ToUnderlying, ToScalar, $ratio>> + Vector<Scalar = Scalar>,
+            Underlying::Width: Multiply<$n>,
+            ToUnderlying: Vector<Scalar = ToScalar>,
+            ToUnderlying::Width: Multiply<$ratio> + Multiply<$m>,
+            Scalar: Copy,
+            ToScalar: Copy,
+        {
+            $(
+            #[inline]
+            fn $convert(self, token: ToUnderlying::Token) -> Shim<ToUnderlying, ToScalar, $m> {
+                let mut converted = [ToUnderlying::zeroed(token); $m];
+                for (to, from) in converted.chunks_mut($ratio).zip(self.0.iter()) {
+                    to.copy_from_slice(&from.$convert(token).0);
+                }
+                Shim(converted, PhantomData)
+            }
+            )*
+        }
+    };
+    {
+        @narrow $n:literal * $ratio:literal = $m:literal, $trait:ident, $($convert:ident),*
+    } => {
+        impl<Underlying, Scalar, ToUnderlying, ToScalar>
+            $trait<Shim<ToUnderlying, ToScalar, $n>> for Shim<Underlying, Scalar, $m>
+        where
+            Shim<Underlying, Scalar, $ratio>: $trait<ToUnderlying>,
+            Underlying: Vector<Scalar = Scalar>,
+            Underlying::Width: Multiply<$ratio> + Multiply<$m>,
+            ToUnderlying: Vector<Scalar = ToScalar>,
+            ToUnderlying::Width: Multiply<$n>,
+            Scalar: Copy,
+            ToScalar: Copy,
+        {
+            $(
+            #[inline]
+            fn $convert(self, token: ToUnderlying::Token) -> Shim<ToUnderlying, ToScalar, $n> {
+                let mut converted = [ToUnderlying::zeroed(token); $n];
+                for (to, from) in converted.iter_mut().zip(self.0.chunks($ratio)) {
+                    let mut parts = [from[0]; $ratio];
+                    parts.copy_from_slice(from);
+                    *to = Shim::<Underlying, Scalar, $ratio>(parts, PhantomData).$convert(token);
+                }
+                Shim(converted, PhantomData)
+            }
+            )*
+        }
+    };
+    {
+        $($n:literal * $ratio:literal = $m:literal),*
+    } => {
+        $(
+        convert_ratio! { @widen $n * $ratio = $m, Convert, convert }
+        convert_ratio! { @narrow $n * $ratio = $m, Convert, convert }
+        convert_ratio! {
+            @widen $n * $ratio = $m, ConvertToInt, convert_truncate, convert_round, convert_saturate
+        }
+        convert_ratio! {
+            @narrow $n * $ratio = $m, ConvertToInt, convert_truncate, convert_round, convert_saturate
+        }
+        )*
+    };
+}
+
+convert_ratio! {
+    2 * 2 = 4,
+    2 * 4 = 8,
+    2 * 8 = 16,
+    2 * 16 = 32,
+    4 * 2 = 8,
+    4 * 4 = 16,
+    4 * 8 = 32,
+    8 * 2 = 16,
+    8 * 4 = 32,
+    16 * 2 = 32
+}
+
 #[cfg(feature = "complex")]
-impl<Underlying, Real> Complex for Shim2<Underlying, num_complex::Complex<Real>>
+impl<Underlying, Real, const N: usize> Complex for Shim<Underlying, num_complex::Complex<Real>, N>
 where
     Underlying: Vector<Scalar = num_complex::Complex<Real>> + Complex<RealScalar = Real>,
-    Underlying::Width: Double,
+    Underlying::Width: Multiply<N>,
     Real: Copy,
 {
     type RealScalar = Real;
 
     #[inline]
     fn conj(self) -> Self {
-        Self([self.0[0].conj(), self.0[1].conj()], PhantomData)
+        self.map(Underlying::conj)
     }
 
     #[inline]
     fn mul_i(self) -> Self {
-        Self([self.0[0].mul_i(), self.0[1].mul_i()], PhantomData)
+        self.map(Underlying::mul_i)
     }
 
     #[inline]
     fn mul_neg_i(self) -> Self {
-        Self([self.0[0].mul_neg_i(), self.0[1].mul_neg_i()], PhantomData)
+        self.map(Underlying::mul_neg_i)
     }
 }
@@ -2,8 +2,8 @@
 
 use crate::arch::Token;
 use crate::{
-    scalar::Scalar,
-    vector::{width, Native, NativeWidth, Vector},
+    scalar::{Float, FromIndex, Scalar},
+    vector::{width, Classify, Native, NativeWidth, Ops, PrefixSum, Vector},
 };
 use core::marker::PhantomData;
 
@@ -57,6 +57,156 @@ where
 
     /// Create a mutable slice of overlapping vectors from a slice of scalars.
     fn overlapping_mut(&mut self, token: Token) -> OverlappingMut<'_, Self::Vector>;
+
+    /// Fill the slice with `[start, start + step, start + 2 * step, ...]`.
+    ///
+    /// Each element is computed from its index, as if by [`ramp`], so errors do not accumulate.
+    /// The first element is exactly `start`, even if `step` is infinite.
+    ///
+    /// [`ramp`]: ../vector/trait.Vector.html#method.ramp
+    #[inline]
+    fn arange(
+        &mut self,
+        token: Token,
+        start: <Self::Vector as Vector>::Scalar,
+        step: <Self::Vector as Vector>::Scalar,
+    ) where
+        Self::Vector: Ops,
+        <Self::Vector as Vector>::Scalar: FromIndex,
+    {
+        let iota = Self::Vector::iota(token);
+        let ramp = |index: usize| -> Self::Vector {
+            let index: <Self::Vector as Vector>::Scalar = FromIndex::from_index(index);
+            (iota + index) * step + start
+        };
+
+        let (head, vectors, tail) = self.align_mut(token);
+        let head_len = head.len();
+        let tail_index = head_len + vectors.len() * Self::Vector::width();
+        head.copy_from_slice(&ramp(0)[..head_len]);
+        for (i, v) in vectors.iter_mut().enumerate() {
+            *v = ramp(head_len + i * Self::Vector::width());
+        }
+        let tail_len = tail.len();
+        tail.copy_from_slice(&ramp(tail_index)[..tail_len]);
+
+        // Set the first element exactly, since `0 * step` is NaN if `step` is infinite.
+        let (head, vectors, tail) = self.align_mut(token);
+        if let Some(first) = head.first_mut() {
+            *first = start;
+        } else if let Some(first) = vectors.first_mut() {
+            first[0] = start;
+        } else if let Some(first) = tail.first_mut() {
+            *first = start;
+        }
+    }
+
+    /// Fill the slice with evenly spaced values from `start` to `end`, inclusive.
+    ///
+    /// If the slice contains a single element, it is set to `start`.
+    #[inline]
+    fn linspace(
+        &mut self,
+        token: Token,
+        start: <Self::Vector as Vector>::Scalar,
+        end: <Self::Vector as Vector>::Scalar,
+    ) where
+        Self::Vector: Ops,
+        <Self::Vector as Vector>::Scalar: FromIndex
+            + core::ops::Sub<Output = <Self::Vector as Vector>::Scalar>
+            + core::ops::Div<Output = <Self::Vector as Vector>::Scalar>,
+    {
+        let (head, vectors, tail) = self.align_mut(token);
+        let len = head.len() + vectors.len() * Self::Vector::width() + tail.len();
+        if len > 1 {
+            let step = (end - start) / FromIndex::from_index(len - 1);
+            self.arange(token, start, step);
+
+            // Avoid rounding error in the final element.
+            let (head, vectors, tail) = self.align_mut(token);
+            if let Some(last) = tail.last_mut() {
+                *last = end;
+            } else if let Some(last) = vectors.last_mut() {
+                last[Self::Vector::width() - 1] = end;
+            } else if let Some(last) = head.last_mut() {
+                *last = end;
+            }
+        } else {
+            // There is at most one element, which is the start.
+            self.arange(token, start, start);
+        }
+    }
+
+    /// Replace each element with the sum of it and all preceding elements.
+    ///
+    /// See [`prefix_sum`](../vector/trait.PrefixSum.html#method.prefix_sum).
+    #[inline]
+    fn prefix_sum(&mut self, token: Token)
+    where
+        Self::Vector: PrefixSum,
+    {
+        let (head, vectors, tail) = self.align_mut(token);
+        let mut total = Self::Vector::zeroed(token);
+        scan_partial(token, head, &mut total, false);
+        for v in vectors {
+            *v = v.prefix_sum() + total;
+            total = Self::Vector::splat(token, v[Self::Vector::width() - 1]);
+        }
+        scan_partial(token, tail, &mut total, false);
+    }
+
+    /// Replace each element with the sum of all preceding elements.
+    ///
+    /// See [`exclusive_prefix_sum`](../vector/trait.PrefixSum.html#method.exclusive_prefix_sum).
+    #[inline]
+    fn exclusive_prefix_sum(&mut self, token: Token)
+    where
+        Self::Vector: PrefixSum,
+    {
+        let (head, vectors, tail) = self.align_mut(token);
+        let mut total = Self::Vector::zeroed(token);
+        scan_partial(token, head, &mut total, true);
+        for v in vectors {
+            let scanned = v.exclusive_prefix_sum() + total;
+            total = Self::Vector::splat(token, (scanned + *v)[Self::Vector::width() - 1]);
+            *v = scanned;
+        }
+        scan_partial(token, tail, &mut total, true);
+    }
+
+    /// Returns `true` if any element is NaN or infinite.
+    #[inline]
+    fn any_non_finite(&self, token: Token) -> bool
+    where
+        Self::Vector: Classify,
+        <Self::Vector as Vector>::Scalar: Float,
+        Width: width::ArrayOf<bool>,
+    {
+        self.first_non_finite(token).is_some()
+    }
+
+    /// Returns the index of the first element that is NaN or infinite.
+    #[inline]
+    fn first_non_finite(&self, token: Token) -> Option<usize>
+    where
+        Self::Vector: Classify,
+        <Self::Vector as Vector>::Scalar: Float,
+        Width: width::ArrayOf<bool>,
+    {
+        let (head, vectors, tail) = self.align(token);
+        if let Some(index) = head.iter().position(|x| !x.is_finite()) {
+            return Some(index);
+        }
+        for (i, v) in vectors.iter().enumerate() {
+            if let Some(lane) = v.is_finite().as_ref().iter().position(|finite| !finite) {
+                return Some(head.len() + i * Self::Vector::width() + lane);
+            }
+        }
+        let tail_index = head.len() + vectors.len() * Self::Vector::width();
+        tail.iter()
+            .position(|x| !x.is_finite())
+            .map(|index| tail_index + index)
+    }
 }
 
 impl<T, Token, Width> Slice<Token, Width> for [T]
@@ -117,19 +267,30 @@ where
 macro_rules! slice_impl {
     {
         $width:literal,
+        [$($generics:tt)*],
         $width_type:ty,
+        where [$($bound:tt)*],
         $read_unchecked:ident,
         $read:ident,
         $align:ident,
         $align_mut:ident,
         $overlapping:ident,
-        $overlapping_mut:ident
+        $overlapping_mut:ident,
+        $arange:ident,
+        $linspace:ident,
+        $prefix_sum:ident,
+        $exclusive_prefix_sum:ident,
+        $any_non_finite:ident,
+        $first_non_finite:ident
     } => {
         #[doc = "Read a vector with "]
         #[doc = $width]
-        #[doc = " from a slice without checking the length.\n\nSee [`read_unchecked`](../vector/trait.Vector.html#method.read_ptr)."]
+        #[doc = " from a slice without checking the length.\n\n# Safety\nSee [`read_unchecked`](../vector/trait.Vector.html#method.read_ptr)."]
         #[inline]
-        unsafe fn $read_unchecked(&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector {
+        unsafe fn $read_unchecked<$($generics)*>(&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            $($bound)*
+        {
             <Self as Slice<Token, $width_type>>::read_unchecked(self, token)
         }
 
@@ -137,7 +298,10 @@ macro_rules! slice_impl {
         #[doc = $width]
         #[doc = " from a slice.\n\nSee [`read`](../vector/trait.Vector.html#method.read)."]
         #[inline]
-        fn $read(&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector {
+        fn $read<$($generics)*>(&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            $($bound)*
+        {
             <Self as Slice<Token, $width_type>>::read(self, token)
         }
 
@@ -146,12 +310,15 @@ macro_rules! slice_impl {
         #[doc = ".\n\nSee [`align`](trait.Slice.html#tymethod.align)."]
         #[allow(clippy::type_complexity)]
         #[inline]
-        fn $align(&self, token: Token) ->
+        fn $align<$($generics)*>(&self, token: Token) ->
         (
             &[<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
             &[<Self as Slice<Token, $width_type>>::Vector],
             &[<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
-        ) {
+        )
+        where
+            $($bound)*
+        {
             <Self as Slice<Token, $width_type>>::align(self, token)
         }
 
@@ -160,12 +327,15 @@ macro_rules! slice_impl {
         #[doc = ".\n\nSee [`align_mut`](trait.Slice.html#tymethod.align_mut)."]
         #[allow(clippy::type_complexity)]
         #[inline]
-        fn $align_mut(&mut self, token: Token) ->
+        fn $align_mut<$($generics)*>(&mut self, token: Token) ->
         (
             &mut [<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
             &mut [<Self as Slice<Token, $width_type>>::Vector],
             &mut [<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
-        ){
+        )
+        where
+            $($bound)*
+        {
             <Self as Slice<Token, $width_type>>::align_mut(self, token)
         }
 
@@ -173,7 +343,10 @@ macro_rules! slice_impl {
         #[doc = $width]
         #[doc = "from a slice of scalars.\n\nSee [`overlapping`](trait.Slice.html#tymethod.overlapping)."]
         #[inline]
-        fn $overlapping(&self, token: Token) -> Overlapping<'_, <Self as Slice<Token, $width_type>>::Vector> {
+        fn $overlapping<$($generics)*>(&self, token: Token) -> Overlapping<'_, <Self as Slice<Token, $width_type>>::Vector>
+        where
+            $($bound)*
+        {
             <Self as Slice<Token, $width_type>>::overlapping(self, token)
         }
 
@@ -181,12 +354,262 @@ macro_rules! slice_impl {
         #[doc = $width]
         #[doc = "from a slice of scalars.\n\nSee [`overlapping_mut`](trait.Slice.html#tymethod.overlapping_mut)."]
         #[inline]
-        fn $overlapping_mut(
+        fn $overlapping_mut<$($generics)*>(
             &mut self,
             token: Token,
-        ) -> OverlappingMut<'_, <Self as Slice<Token, $width_type>>::Vector> {
+        ) -> OverlappingMut<'_, <Self as Slice<Token, $width_type>>::Vector>
+        where
+            $($bound)*
+        {
             <Self as Slice<Token, $width_type>>::overlapping_mut(self, token)
         }
+
+        #[doc = "Fill a slice with a ramp, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`arange`](trait.Slice.html#method.arange)."]
+        #[inline]
+        fn $arange<$($generics)*>(
+            &mut self,
+            token: Token,
+            start: <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar,
+            step: <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar,
+        ) where
+            $($bound)*
+            <Self as Slice<Token, $width_type>>::Vector: Ops,
+            <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar: FromIndex,
+        {
+            <Self as Slice<Token, $width_type>>::arange(self, token, start, step)
+        }
+
+        #[doc = "Fill a slice with evenly spaced values, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`linspace`](trait.Slice.html#method.linspace)."]
+        #[inline]
+        fn $linspace<$($generics)*>(
+            &mut self,
+            token: Token,
+            start: <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar,
+            end: <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar,
+        ) where
+            $($bound)*
+            <Self as Slice<Token, $width_type>>::Vector: Ops,
+            <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar: FromIndex
+                + core::ops::Sub<Output = <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar>
+                + core::ops::Div<Output = <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar>,
+        {
+            <Self as Slice<Token, $width_type>>::linspace(self, token, start, end)
+        }
+
+        #[doc = "Replace each element with the sum of it and all preceding elements, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`prefix_sum`](trait.Slice.html#method.prefix_sum)."]
+        #[inline]
+        fn $prefix_sum<$($generics)*>(&mut self, token: Token)
+        where
+            $($bound)*
+            <Self as Slice<Token, $width_type>>::Vector: PrefixSum,
+        {
+            <Self as Slice<Token, $width_type>>::prefix_sum(self, token)
+        }
+
+        #[doc = "Replace each element with the sum of all preceding elements, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`exclusive_prefix_sum`](trait.Slice.html#method.exclusive_prefix_sum)."]
+        #[inline]
+        fn $exclusive_prefix_sum<$($generics)*>(&mut self, token: Token)
+        where
+            $($bound)*
+            <Self as Slice<Token, $width_type>>::Vector: PrefixSum,
+        {
+            <Self as Slice<Token, $width_type>>::exclusive_prefix_sum(self, token)
+        }
+
+        #[doc = "Returns `true` if any element is NaN or infinite, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`any_non_finite`](trait.Slice.html#method.any_non_finite)."]
+        #[inline]
+        fn $any_non_finite<$($generics)*>(&self, token: Token) -> bool
+        where
+            $($bound)*
+            <Self as Slice<Token, $width_type>>::Vector: Classify,
+            <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar: Float,
+            $width_type: width::ArrayOf<bool>,
+        {
+            <Self as Slice<Token, $width_type>>::any_non_finite(self, token)
+        }
+
+        #[doc = "Returns the index of the first element that is NaN or infinite, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`first_non_finite`](trait.Slice.html#method.first_non_finite)."]
+        #[inline]
+        fn $first_non_finite<$($generics)*>(&self, token: Token) -> Option<usize>
+        where
+            $($bound)*
+            <Self as Slice<Token, $width_type>>::Vector: Classify,
+            <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar: Float,
+            $width_type: width::ArrayOf<bool>,
+        {
+            <Self as Slice<Token, $width_type>>::first_non_finite(self, token)
+        }
+    }
+}
+
+macro_rules! slice_lanes_impl {
+    {
+        $width:literal,
+        $lanes:literal,
+        $read_unchecked:ident,
+        $read:ident,
+        $align:ident,
+        $align_mut:ident,
+        $overlapping:ident,
+        $overlapping_mut:ident,
+        $arange:ident,
+        $linspace:ident,
+        $prefix_sum:ident,
+        $exclusive_prefix_sum:ident,
+        $any_non_finite:ident,
+        $first_non_finite:ident
+    } => {
+        #[doc = "Read a vector with "]
+        #[doc = $width]
+        #[doc = " from a slice without checking the length.\n\n# Safety\nSee [`read_unchecked_lanes`](#method.read_unchecked_lanes)."]
+        #[inline]
+        unsafe fn $read_unchecked(&self, token: Token) -> <Self as Slice<Token, width::Lanes<$lanes>>>::Vector {
+            self.read_unchecked_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Read a vector with "]
+        #[doc = $width]
+        #[doc = " from a slice.\n\nSee [`read_lanes`](#method.read_lanes)."]
+        #[inline]
+        fn $read(&self, token: Token) -> <Self as Slice<Token, width::Lanes<$lanes>>>::Vector {
+            self.read_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Align a slice of scalars to vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`align_lanes`](#method.align_lanes)."]
+        #[allow(clippy::type_complexity)]
+        #[inline]
+        fn $align(&self, token: Token) ->
+        (
+            &[<<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar],
+            &[<Self as Slice<Token, width::Lanes<$lanes>>>::Vector],
+            &[<<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar],
+        ) {
+            self.align_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Align a slice of scalars to vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`align_lanes_mut`](#method.align_lanes_mut)."]
+        #[allow(clippy::type_complexity)]
+        #[inline]
+        fn $align_mut(&mut self, token: Token) ->
+        (
+            &mut [<<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar],
+            &mut [<Self as Slice<Token, width::Lanes<$lanes>>>::Vector],
+            &mut [<<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar],
+        ) {
+            self.align_lanes_mut::<$lanes>(token)
+        }
+
+        #[doc = "Create a slice of overlapping vectors of "]
+        #[doc = $width]
+        #[doc = " from a slice of scalars.\n\nSee [`overlapping_lanes`](#method.overlapping_lanes)."]
+        #[inline]
+        fn $overlapping(&self, token: Token) -> Overlapping<'_, <Self as Slice<Token, width::Lanes<$lanes>>>::Vector> {
+            self.overlapping_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Create a mutable slice of overlapping vectors of "]
+        #[doc = $width]
+        #[doc = " from a slice of scalars.\n\nSee [`overlapping_lanes_mut`](#method.overlapping_lanes_mut)."]
+        #[inline]
+        fn $overlapping_mut(&mut self, token: Token) -> OverlappingMut<'_, <Self as Slice<Token, width::Lanes<$lanes>>>::Vector> {
+            self.overlapping_lanes_mut::<$lanes>(token)
+        }
+
+        #[doc = "Fill a slice with a ramp, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`arange_lanes`](#method.arange_lanes)."]
+        #[inline]
+        fn $arange(
+            &mut self,
+            token: Token,
+            start: <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar,
+            step: <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar,
+        ) where
+            <Self as Slice<Token, width::Lanes<$lanes>>>::Vector: Ops,
+            <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar: FromIndex,
+        {
+            self.arange_lanes::<$lanes>(token, start, step)
+        }
+
+        #[doc = "Fill a slice with evenly spaced values, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`linspace_lanes`](#method.linspace_lanes)."]
+        #[inline]
+        fn $linspace(
+            &mut self,
+            token: Token,
+            start: <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar,
+            end: <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar,
+        ) where
+            <Self as Slice<Token, width::Lanes<$lanes>>>::Vector: Ops,
+            <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar: FromIndex
+                + core::ops::Sub<Output = <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar>
+                + core::ops::Div<Output = <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar>,
+        {
+            self.linspace_lanes::<$lanes>(token, start, end)
+        }
+
+        #[doc = "Replace each element with the sum of it and all preceding elements, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`prefix_sum_lanes`](#method.prefix_sum_lanes)."]
+        #[inline]
+        fn $prefix_sum(&mut self, token: Token)
+        where
+            <Self as Slice<Token, width::Lanes<$lanes>>>::Vector: PrefixSum,
+        {
+            self.prefix_sum_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Replace each element with the sum of all preceding elements, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`exclusive_prefix_sum_lanes`](#method.exclusive_prefix_sum_lanes)."]
+        #[inline]
+        fn $exclusive_prefix_sum(&mut self, token: Token)
+        where
+            <Self as Slice<Token, width::Lanes<$lanes>>>::Vector: PrefixSum,
+        {
+            self.exclusive_prefix_sum_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Returns `true` if any element is NaN or infinite, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`any_non_finite_lanes`](#method.any_non_finite_lanes)."]
+        #[inline]
+        fn $any_non_finite(&self, token: Token) -> bool
+        where
+            <Self as Slice<Token, width::Lanes<$lanes>>>::Vector: Classify,
+            <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar: Float,
+        {
+            self.any_non_finite_lanes::<$lanes>(token)
+        }
+
+        #[doc = "Returns the index of the first element that is NaN or infinite, using vectors with "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`first_non_finite_lanes`](#method.first_non_finite_lanes)."]
+        #[inline]
+        fn $first_non_finite(&self, token: Token) -> Option<usize>
+        where
+            <Self as Slice<Token, width::Lanes<$lanes>>>::Vector: Classify,
+            <<Self as Slice<Token, width::Lanes<$lanes>>>::Vector as Vector>::Scalar: Float,
+        {
+            self.first_non_finite_lanes::<$lanes>(token)
+        }
     }
 }
 
@@ -204,15 +627,20 @@ pub trait SliceExt<Token>:
     + Slice<Token, width::W2>
     + Slice<Token, width::W4>
     + Slice<Token, width::W8>
+    + Slice<Token, width::W16>
+    + Slice<Token, width::W32>
     + Slice<Token, NativeWidth<Self, Token>>
 where
     Token: crate::arch::Token,
 {
-    slice_impl! { "the native number of lanes", <Self as Native<Token>>::Width, read_unchecked_native, read_native, align_native, align_native_mut, overlapping_native, overlapping_native_mut }
-    slice_impl! { "1 lane",   width::W1, read_unchecked1, read1, align1, align1_mut, overlapping1, overlapping1_mut }
-    slice_impl! { "2 lanes",  width::W2, read_unchecked2, read2, align2, align2_mut, overlapping2, overlapping2_mut }
-    slice_impl! { "4 lanes",  width::W4, read_unchecked4, read4, align4, align4_mut, overlapping4, overlapping4_mut }
-    slice_impl! { "8 lanes",  width::W8, read_unchecked8, read8, align8, align8_mut, overlapping8, overlapping8_mut }
+    slice_impl! { "the native number of lanes", [], <Self as Native<Token>>::Width, where [], read_unchecked_native, read_native, align_native, align_native_mut, overlapping_native, overlapping_native_mut, arange_native, linspace_native, prefix_sum_native, exclusive_prefix_sum_native, any_non_finite_native, first_non_finite_native }
+    slice_impl! { "`N` lanes", [const N: usize], width::Lanes<N>, where [Self: Slice<Token, width::Lanes<N>>,], read_unchecked_lanes, read_lanes, align_lanes, align_lanes_mut, overlapping_lanes, overlapping_lanes_mut, arange_lanes, linspace_lanes, prefix_sum_lanes, exclusive_prefix_sum_lanes, any_non_finite_lanes, first_non_finite_lanes }
+    slice_lanes_impl! { "1 lane", 1, read_unchecked1, read1, align1, align1_mut, overlapping1, overlapping1_mut, arange1, linspace1, prefix_sum1, exclusive_prefix_sum1, any_non_finite1, first_non_finite1 }
+    slice_lanes_impl! { "2 lanes", 2, read_unchecked2, read2, align2, align2_mut, overlapping2, overlapping2_mut, arange2, linspace2, prefix_sum2, exclusive_prefix_sum2, any_non_finite2, first_non_finite2 }
+    slice_lanes_impl! { "4 lanes", 4, read_unchecked4, read4, align4, align4_mut, overlapping4, overlapping4_mut, arange4, linspace4, prefix_sum4, exclusive_prefix_sum4, any_non_finite4, first_non_finite4 }
+    slice_lanes_impl! { "8 lanes", 8, read_unchecked8, read8, align8, align8_mut, overlapping8, overlapping8_mut, arange8, linspace8, prefix_sum8, exclusive_prefix_sum8, any_non_finite8, first_non_finite8 }
+    slice_lanes_impl! { "16 lanes", 16, read_unchecked16, read16, align16, align16_mut, overlapping16, overlapping16_mut, arange16, linspace16, prefix_sum16, exclusive_prefix_sum16, any_non_finite16, first_non_finite16 }
+    slice_lanes_impl! { "32 lanes", 32, read_unchecked32, read32, align32, align32_mut, overlapping32, overlapping32_mut, arange32, linspace32, prefix_sum32, exclusive_prefix_sum32, any_non_finite32, first_non_finite32 }
 }
 
 impl<T, Token> SliceExt<Token> for T
@@ -223,11 +651,35 @@ where
         + Slice<Token, width::W2>
         + Slice<Token, width::W4>
         + Slice<Token, width::W8>
+        + Slice<Token, width::W16>
+        + Slice<Token, width::W32>
         + Slice<Token, NativeWidth<Self, Token>>,
     Token: crate::arch::Token,
 {
 }
 
+// Scans a partial vector of scalars, carrying the total of the preceding elements.
+#[inline]
+fn scan_partial<V: PrefixSum>(
+    token: V::Token,
+    scalars: &mut [V::Scalar],
+    total: &mut V,
+    exclusive: bool,
+) {
+    if let Some(last) = scalars.len().checked_sub(1) {
+        let mut v = V::zeroed(token);
+        v[..scalars.len()].copy_from_slice(scalars);
+        let inclusive = v.prefix_sum() + *total;
+        let scanned = if exclusive {
+            v.exclusive_prefix_sum() + *total
+        } else {
+            inclusive
+        };
+        *total = V::splat(token, inclusive[last]);
+        scalars.copy_from_slice(&scanned[..scalars.len()]);
+    }
+}
+
 /// Wrapper for producing a mutable reference from an unaligned pointer.
 pub struct RefMut<'a, V>
 where
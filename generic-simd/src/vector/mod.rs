@@ -1,11 +1,107 @@
 //! Vector type interfaces.
+//!
+//! # Implementing vectors
+//! Other crates can provide their own tokens and vector types, which work with all of the
+//! generic code in this crate.
+//! The [`impl_token!`], [`arithmetic_ops!`], [`bitwise_ops!`], [`shift_ops!`] and [`as_slice!`]
+//! macros implement most of the required traits, leaving only [`Vector`] to implement by hand.
+//! The [`impl_scalar!`] macro implements [`Scalar`] for every width, using
+//! [`Shim`](crate::shim::Shim) for widths without a native vector.
+//!
+//! ```
+//! use generic_simd::{
+//!     arch::{generic, Token},
+//!     arithmetic_ops, as_slice, bitwise_ops, impl_scalar, impl_token,
+//!     scalar::ScalarExt,
+//!     shim::{Shim, ShimToken},
+//!     vector::{width, Classify, Horizontal, PrefixSum, Vector},
+//! };
+//!
+//! /// A token that is always supported.
+//! #[derive(Copy, Clone, Debug)]
+//! pub struct MyToken(());
+//!
+//! // Safety: `MyToken` can only be constructed through `Token`, and uses no CPU features.
+//! impl_token! { unsafe MyToken }
+//!
+//! /// A vector of 4 `f32`s.
+//! #[derive(Copy, Clone, Debug)]
+//! #[repr(transparent)]
+//! pub struct MyVector([f32; 4]);
+//!
+//! arithmetic_ops! {
+//!     for MyVector:
+//!         add -> (),
+//!         sub -> (),
+//!         mul -> (),
+//!         div -> ()
+//! }
+//!
+//! bitwise_ops! {
+//!     for MyVector:
+//!         and -> (),
+//!         or -> (),
+//!         xor -> (),
+//!         not -> (),
+//!         and_not -> ()
+//! }
+//!
+//! as_slice! { MyVector }
+//!
+//! impl core::ops::Neg for MyVector {
+//!     type Output = Self;
+//!
+//!     fn neg(mut self) -> Self {
+//!         for x in self.iter_mut() {
+//!             *x = -*x;
+//!         }
+//!         self
+//!     }
+//! }
+//!
+//! impl PrefixSum for MyVector {}
+//! impl Horizontal for MyVector {}
+//! impl Classify for MyVector {}
+//!
+//! // Safety: `MyVector` has the layout of `[f32; 4]` and uses no CPU features.
+//! unsafe impl Vector for MyVector {
+//!     type Scalar = f32;
+//!     type Token = MyToken;
+//!     type Width = width::W4;
+//!     type Underlying = [f32; 4];
+//!
+//!     fn zeroed(_: Self::Token) -> Self {
+//!         Self([0.; 4])
+//!     }
+//!
+//!     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+//!         Self([from; 4])
+//!     }
+//! }
+//!
+//! impl_scalar! {
+//!     MyToken, f32, native 4 {
+//!         1 => ShimToken<generic::f32x1, Self, MyToken>,
+//!         2 => ShimToken<Shim<generic::f32x1, Self, 2>, Self, MyToken>,
+//!     }
+//!     4.. => MyVector
+//! }
+//!
+//! let token = MyToken::new().unwrap();
+//! let x = MyVector::splat(token, 2.) * 3.;
+//! assert_eq!(x.as_slice(), &[6.; 4]);
+//! assert_eq!(2f32.splat_lanes::<16>(token).as_slice(), &[2.; 16]);
+//! ```
 
 pub mod width;
 
 use crate::arch::Token;
-use crate::scalar::Scalar;
+use crate::pointer::Locality;
+use crate::scalar::{Bitcast, Float, FromIndex, Scalar};
 use core::ops::{
-    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref,
+    DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub,
+    SubAssign,
 };
 
 /// Indicates the widest native vector.
@@ -22,11 +118,42 @@ pub type NativeVector<Scalar, Token> = VectorOf<Scalar, NativeWidth<Scalar, Toke
 /// Convenience type for the vector with a particular width.
 pub type VectorOf<Scalar, Width, Token> = <Scalar as self::Scalar<Token, Width>>::Vector;
 
+/// Convenience type for the array with the same lanes as a vector.
+pub type VectorArray<Vector> =
+    <<Vector as self::Vector>::Width as width::ArrayOf<<Vector as self::Vector>::Scalar>>::Array;
+
+/// Convenience type for the array of `bool` with one element per lane of a vector.
+pub type LaneMask<Vector> = <<Vector as self::Vector>::Width as width::ArrayOf<bool>>::Array;
+
+/// Create a lane mask with each element containing the result of calling `f` with the lane index.
+#[inline]
+pub(crate) fn lane_mask_from_fn<V, F>(mut f: F) -> LaneMask<V>
+where
+    V: Vector,
+    V::Width: width::ArrayOf<bool>,
+    F: FnMut(usize) -> bool,
+{
+    // An array of `false` is all zeros.
+    let mut mask: LaneMask<V> = unsafe { core::mem::zeroed() };
+    for (i, lane) in mask.as_mut().iter_mut().enumerate() {
+        *lane = f(i);
+    }
+    mask
+}
+
 /// The fundamental vector type.
 ///
 /// # Safety
-/// This trait may only be implemented for types that have the memory layout of an array of
-/// `Scalar` with length `width()`.
+/// The provided methods reinterpret vectors as arrays and slices of scalars, and create tokens
+/// from vectors, so implementations must uphold the following:
+/// * The type has the size of an array of `Scalar` with length `width()`, and is aligned at
+///   least as strictly as `Scalar`.
+/// * Every array of `Scalar` with length `width()` is a valid value of the type, and the lanes
+///   of the vector are in the same order as the elements of the array.
+/// * `Underlying` has the same size and alignment as the type.
+/// * `Width` is the number of lanes in the vector.
+/// * `Token` guarantees every CPU feature used by the implementation, since a vector can be
+///   converted to its token with [`to_token`](Vector::to_token).
 pub unsafe trait Vector: Copy {
     /// The type of elements in the vector.
     type Scalar: Copy;
@@ -72,7 +199,7 @@ pub unsafe trait Vector: Copy {
                 core::mem::size_of::<Self::Underlying>(),
                 core::mem::align_of::<Self::Underlying>(),
             ),
-            (core::mem::align_of::<Self>(), core::mem::size_of::<Self>(),)
+            (core::mem::size_of::<Self>(), core::mem::align_of::<Self>())
         );
         unsafe { core::mem::transmute_copy(&self) }
     }
@@ -88,11 +215,38 @@ pub unsafe trait Vector: Copy {
                 core::mem::size_of::<Self::Underlying>(),
                 core::mem::align_of::<Self::Underlying>(),
             ),
-            (core::mem::align_of::<Self>(), core::mem::size_of::<Self>(),)
+            (core::mem::size_of::<Self>(), core::mem::align_of::<Self>())
         );
         unsafe { core::mem::transmute_copy(&underlying) }
     }
 
+    /// Reinterprets the bits of this vector as a vector of another scalar type.
+    ///
+    /// The destination vector must have the same token and number of lanes, and the scalars must
+    /// implement [`Bitcast`], which is verified at compile time.
+    ///
+    /// ```
+    /// use generic_simd::{
+    ///     arch::generic::Generic,
+    ///     scalar::ScalarExt,
+    ///     vector::{width, Vector, VectorOf},
+    /// };
+    ///
+    /// let bits: VectorOf<u32, width::W4, Generic> = 1f32.splat_lanes::<4>(Generic).bitcast();
+    /// assert!(bits.iter().all(|&x| x == 1f32.to_bits()));
+    /// ```
+    ///
+    /// [`Bitcast`]: ../scalar/trait.Bitcast.html
+    #[inline]
+    fn bitcast<To>(self) -> To
+    where
+        To: Vector<Token = Self::Token, Width = Self::Width>,
+        Self::Scalar: Bitcast<To::Scalar>,
+    {
+        // Both vectors are arrays of `width()` scalars with the same size.
+        unsafe { (&self as *const Self as *const To).read_unaligned() }
+    }
+
     /// Read from a pointer.
     ///
     /// # Safety
@@ -161,6 +315,53 @@ pub unsafe trait Vector: Copy {
         (to as *mut Self).write(self);
     }
 
+    /// Write to a vector-aligned pointer with a non-temporal store.
+    ///
+    /// Non-temporal stores minimize cache pollution when writing large buffers that will not be
+    /// read again soon.  If the architecture does not support non-temporal stores, this is
+    /// equivalent to [`write_aligned_ptr`](#method.write_aligned_ptr).
+    ///
+    /// Non-temporal stores are weakly ordered: use [`stream_fence`](#method.stream_fence)
+    /// before the written memory is accessed by another thread.
+    ///
+    /// # Safety
+    /// `to` must point to an array of length at least `width()`
+    /// `to` must be aligned for the vector type.
+    #[inline]
+    unsafe fn write_stream_aligned_ptr(self, to: *mut Self::Scalar) {
+        self.write_aligned_ptr(to);
+    }
+
+    /// Orders all preceding non-temporal stores before any subsequent stores.
+    ///
+    /// If the architecture does not support non-temporal stores, this does nothing.
+    #[inline]
+    fn stream_fence(#[allow(unused_variables)] token: Self::Token) {}
+
+    /// Hint that the vector at a pointer will be read soon.
+    ///
+    /// Prefetching is only a hint and never dereferences the pointer, so it is always safe.
+    /// If the architecture does not support prefetching, this does nothing.
+    #[inline]
+    fn prefetch_read_ptr(
+        #[allow(unused_variables)] token: Self::Token,
+        #[allow(unused_variables)] from: *const Self::Scalar,
+        #[allow(unused_variables)] locality: Locality,
+    ) {
+    }
+
+    /// Hint that the vector at a pointer will be written soon.
+    ///
+    /// Prefetching is only a hint and never dereferences the pointer, so it is always safe.
+    /// If the architecture does not support prefetching, this does nothing.
+    #[inline]
+    fn prefetch_write_ptr(
+        #[allow(unused_variables)] token: Self::Token,
+        #[allow(unused_variables)] to: *const Self::Scalar,
+        #[allow(unused_variables)] locality: Locality,
+    ) {
+    }
+
     /// Write to a slice without checking the length.
     ///
     /// # Safety
@@ -188,6 +389,94 @@ pub unsafe trait Vector: Copy {
 
     /// Create a new vector with each lane containing the provided value.
     fn splat(token: Self::Token, from: Self::Scalar) -> Self;
+
+    /// Create a new vector from an array containing each lane.
+    #[inline]
+    fn from_array(token: Self::Token, from: VectorArray<Self>) -> Self
+    where
+        Self::Width: width::ArrayOf<Self::Scalar>,
+    {
+        unsafe { Self::read_ptr(token, &from as *const _ as *const Self::Scalar) }
+    }
+
+    /// Returns an array containing each lane.
+    #[inline]
+    fn to_array(self) -> VectorArray<Self>
+    where
+        Self::Width: width::ArrayOf<Self::Scalar>,
+    {
+        unsafe { (&self as *const Self as *const VectorArray<Self>).read_unaligned() }
+    }
+
+    /// Create a new vector with each lane containing the result of calling `f` with the lane
+    /// index.
+    #[inline]
+    fn from_fn<F>(token: Self::Token, mut f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Scalar,
+    {
+        let mut v = Self::zeroed(token);
+        for (i, lane) in v.as_slice_mut().iter_mut().enumerate() {
+            *lane = f(i);
+        }
+        v
+    }
+
+    /// Create a new vector with each lane containing its index, i.e. `[0, 1, 2, ...]`.
+    #[inline]
+    fn iota(token: Self::Token) -> Self
+    where
+        Self::Scalar: FromIndex,
+    {
+        Self::from_fn(token, Self::Scalar::from_index)
+    }
+
+    /// Create a new vector with lanes `[start, start + step, start + 2 * step, ...]`.
+    #[inline]
+    fn ramp(token: Self::Token, start: Self::Scalar, step: Self::Scalar) -> Self
+    where
+        Self: Ops,
+        Self::Scalar: FromIndex,
+    {
+        Self::iota(token) * step + start
+    }
+
+    /// Returns the value of lane `I`.
+    ///
+    /// Fails to compile if `I` is not less than `width()`.
+    ///
+    /// ```compile_fail
+    /// use generic_simd::{arch::generic::Generic, vector::{width, Vector, VectorOf}};
+    ///
+    /// let v: VectorOf<f64, width::W2, Generic> = [1., 2.].into();
+    /// v.extract::<2>();
+    /// ```
+    #[inline]
+    fn extract<const I: usize>(self) -> Self::Scalar {
+        #[allow(clippy::let_unit_value)]
+        let _ = LaneIndex::<Self::Width, I>::VALID;
+        self.as_slice()[I]
+    }
+
+    /// Returns a copy of this vector with lane `I` set to `value`.
+    ///
+    /// Fails to compile if `I` is not less than `width()`.
+    #[inline]
+    fn replace<const I: usize>(mut self, value: Self::Scalar) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = LaneIndex::<Self::Width, I>::VALID;
+        self.as_slice_mut()[I] = value;
+        self
+    }
+}
+
+// Checks lane indices at compile time.
+struct LaneIndex<W, const I: usize>(core::marker::PhantomData<W>);
+
+impl<W: width::Width, const I: usize> LaneIndex<W, I> {
+    // Evaluating this constant fails when the index is out of range.  Panicking in constants
+    // requires a newer compiler than the minimum supported version, so this indexes out of bounds.
+    const VALID: () = [()][(I >= W::VALUE) as usize];
 }
 
 /// A supertrait for vectors supporting typical arithmetic operations.
@@ -244,6 +533,285 @@ impl<V> Ops for V where
 pub trait Signed: Ops + Neg<Output = Self> {}
 impl<V> Signed for V where V: Ops + Neg<Output = V> {}
 
+/// A supertrait for vectors supporting bitwise operations.
+///
+/// Floating-point vectors operate on the bit representation of each lane.
+pub trait Bitwise:
+    Vector
+    + BitAnd<Self, Output = Self>
+    + BitAnd<<Self as Vector>::Scalar, Output = Self>
+    + BitAndAssign<Self>
+    + BitAndAssign<<Self as Vector>::Scalar>
+    + BitOr<Self, Output = Self>
+    + BitOr<<Self as Vector>::Scalar, Output = Self>
+    + BitOrAssign<Self>
+    + BitOrAssign<<Self as Vector>::Scalar>
+    + BitXor<Self, Output = Self>
+    + BitXor<<Self as Vector>::Scalar, Output = Self>
+    + BitXorAssign<Self>
+    + BitXorAssign<<Self as Vector>::Scalar>
+    + Not<Output = Self>
+{
+    /// Computes `self & !rhs`.
+    #[inline]
+    fn and_not(self, rhs: Self) -> Self {
+        self & !rhs
+    }
+}
+
+/// A supertrait for integer vectors supporting shifts.
+///
+/// Every lane is shifted by the same number of bits.
+/// Right shifts are arithmetic for signed lanes and logical for unsigned lanes.
+/// Shifting by at least the number of bits in a lane saturates, like the native instruction sets:
+/// the result is zero, except for right shifts of negative signed lanes, which produce -1.
+pub trait Shift:
+    Vector + Shl<u32, Output = Self> + ShlAssign<u32> + Shr<u32, Output = Self> + ShrAssign<u32>
+{
+}
+impl<V> Shift for V where
+    V: Vector + Shl<u32, Output = V> + ShlAssign<u32> + Shr<u32, Output = V> + ShrAssign<u32>
+{
+}
+
+/// Vectors supporting prefix sums across lanes.
+pub trait PrefixSum: Ops {
+    /// Computes the inclusive prefix sum of the lanes, i.e. `[a, a + b, a + b + c, ...]`.
+    ///
+    /// The order of the additions is unspecified.
+    #[inline]
+    fn prefix_sum(self) -> Self {
+        // Accumulate with vector addition, since scalars may not implement `Add`.
+        let mut sum = Self::zeroed(self.to_token());
+        let mut scanned = self;
+        for (scanned, x) in scanned.iter_mut().zip(self.iter()) {
+            sum += *x;
+            *scanned = sum[0];
+        }
+        scanned
+    }
+
+    /// Computes the exclusive prefix sum of the lanes, i.e. `[0, a, a + b, ...]`.
+    ///
+    /// The order of the additions is unspecified.
+    #[inline]
+    fn exclusive_prefix_sum(self) -> Self {
+        let mut sum = Self::zeroed(self.to_token());
+        let mut scanned = self;
+        for (scanned, x) in scanned.iter_mut().zip(self.iter()) {
+            *scanned = sum[0];
+            sum += *x;
+        }
+        scanned
+    }
+}
+
+/// Vectors supporting horizontal operations across lanes.
+pub trait Horizontal: Ops {
+    /// Adds adjacent pairs of lanes.
+    ///
+    /// The lanes of `self` followed by the lanes of `rhs` are summed in pairs, i.e.
+    /// `[a0 + a1, a2 + a3, ..., b0 + b1, b2 + b3, ...]`.
+    /// For vectors with a single lane, this is `[a0 + b0]`.
+    #[inline]
+    fn horizontal_add_pairs(self, rhs: Self) -> Self {
+        let width = Self::width();
+        let lane = |i: usize| if i < width { self[i] } else { rhs[i - width] };
+        let token = self.to_token();
+        Self::from_fn(token, |i| lane(2 * i)) + Self::from_fn(token, |i| lane(2 * i + 1))
+    }
+
+    /// Returns the sum of the lanes.
+    ///
+    /// The order of the additions is unspecified.
+    #[inline]
+    fn horizontal_sum(self) -> Self::Scalar {
+        // Accumulate with vector addition, since scalars may not implement `Add`.
+        let mut sum = Self::zeroed(self.to_token());
+        for x in self.iter() {
+            sum += *x;
+        }
+        sum[0]
+    }
+
+    /// Returns the dot product of two vectors, i.e. the sum of the lanes of `self * rhs`.
+    ///
+    /// Complex vectors are not conjugated.
+    /// The order of the additions is unspecified.
+    #[inline]
+    fn dot(self, rhs: Self) -> Self::Scalar {
+        (self * rhs).horizontal_sum()
+    }
+}
+
+/// Floating-point vectors supporting classification of each lane.
+///
+/// Each predicate returns an array containing the result for each lane.
+pub trait Classify: Vector
+where
+    Self::Scalar: Float,
+    Self::Width: width::ArrayOf<bool>,
+{
+    /// Returns which lanes are NaN.
+    #[inline]
+    fn is_nan(self) -> LaneMask<Self> {
+        lane_mask_from_fn::<Self, _>(|i| self.as_slice()[i].is_nan())
+    }
+
+    /// Returns which lanes are positive or negative infinity.
+    #[inline]
+    fn is_infinite(self) -> LaneMask<Self> {
+        lane_mask_from_fn::<Self, _>(|i| self.as_slice()[i].is_infinite())
+    }
+
+    /// Returns which lanes are neither infinite nor NaN.
+    #[inline]
+    fn is_finite(self) -> LaneMask<Self> {
+        lane_mask_from_fn::<Self, _>(|i| self.as_slice()[i].is_finite())
+    }
+
+    /// Returns which lanes are subnormal.
+    #[inline]
+    fn is_subnormal(self) -> LaneMask<Self> {
+        lane_mask_from_fn::<Self, _>(|i| self.as_slice()[i].is_subnormal())
+    }
+
+    /// Returns which lanes have a negative sign bit.
+    #[inline]
+    fn is_sign_negative(self) -> LaneMask<Self> {
+        lane_mask_from_fn::<Self, _>(|i| self.as_slice()[i].is_sign_negative())
+    }
+}
+
+/// A vector, or a scalar that is splatted to every lane of a vector.
+pub trait Operand<V: Vector> {
+    /// Converts this operand to a vector.
+    fn into_vector(self, token: V::Token) -> V;
+}
+
+impl<V: Vector> Operand<V> for V {
+    #[inline]
+    fn into_vector(self, _: V::Token) -> V {
+        self
+    }
+}
+
+impl<V: Vector<Scalar = f32>> Operand<V> for f32 {
+    #[inline]
+    fn into_vector(self, token: V::Token) -> V {
+        V::splat(token, self)
+    }
+}
+
+impl<V: Vector<Scalar = f64>> Operand<V> for f64 {
+    #[inline]
+    fn into_vector(self, token: V::Token) -> V {
+        V::splat(token, self)
+    }
+}
+
+/// Floating-point vectors supporting interpolation.
+///
+/// Each parameter may be either a vector or a scalar.
+pub trait Interpolate: Signed
+where
+    Self::Scalar: Float + From<f32>,
+{
+    /// Clamps each lane to the range `[lo, hi]`.
+    ///
+    /// NaN lanes are unchanged.
+    #[inline]
+    fn clamp<L, H>(self, lo: L, hi: H) -> Self
+    where
+        L: Operand<Self>,
+        H: Operand<Self>,
+    {
+        let token = self.to_token();
+        let lo = lo.into_vector(token);
+        let hi = hi.into_vector(token);
+        let mut clamped = self;
+        for ((x, lo), hi) in clamped.iter_mut().zip(lo.iter()).zip(hi.iter()) {
+            if *x < *lo {
+                *x = *lo;
+            } else if *x > *hi {
+                *x = *hi;
+            }
+        }
+        clamped
+    }
+
+    /// Linearly interpolates from `self` to `b`, i.e. `self + (b - self) * t`.
+    #[inline]
+    fn lerp<B, T>(self, b: B, t: T) -> Self
+    where
+        B: Operand<Self>,
+        T: Operand<Self>,
+    {
+        let token = self.to_token();
+        self + (b.into_vector(token) - self) * t.into_vector(token)
+    }
+
+    /// Returns the interpolation factor of `self` within `a` to `b`, i.e. `(self - a) / (b - a)`.
+    ///
+    /// This is the inverse of [`lerp`](#method.lerp).
+    #[inline]
+    fn inverse_lerp<A, B>(self, a: A, b: B) -> Self
+    where
+        A: Operand<Self>,
+        B: Operand<Self>,
+    {
+        let token = self.to_token();
+        let a = a.into_vector(token);
+        (self - a) / (b.into_vector(token) - a)
+    }
+
+    /// Linearly maps `self` from the range `in_lo` to `in_hi` onto the range `out_lo` to `out_hi`.
+    ///
+    /// The result is not clamped to the output range.
+    #[inline]
+    fn remap<InLo, InHi, OutLo, OutHi>(
+        self,
+        in_lo: InLo,
+        in_hi: InHi,
+        out_lo: OutLo,
+        out_hi: OutHi,
+    ) -> Self
+    where
+        InLo: Operand<Self>,
+        InHi: Operand<Self>,
+        OutLo: Operand<Self>,
+        OutHi: Operand<Self>,
+    {
+        let token = self.to_token();
+        let t = self.inverse_lerp(in_lo, in_hi);
+        out_lo.into_vector(token).lerp(out_hi, t)
+    }
+
+    /// Smooth Hermite interpolation between 0 and 1 as `self` moves from `edge0` to `edge1`.
+    ///
+    /// Computes `t * t * (3 - 2 * t)`, where `t` is `self.inverse_lerp(edge0, edge1)` clamped to
+    /// `[0, 1]`.
+    #[inline]
+    fn smoothstep<E0, E1>(self, edge0: E0, edge1: E1) -> Self
+    where
+        E0: Operand<Self>,
+        E1: Operand<Self>,
+    {
+        let token = self.to_token();
+        let zero = Self::zeroed(token);
+        let one = Self::splat(token, Self::Scalar::from(1.));
+        let t = self.inverse_lerp(edge0, edge1).clamp(zero, one);
+        t * t * (t * Self::Scalar::from(-2.) + Self::Scalar::from(3.))
+    }
+}
+
+impl<V> Interpolate for V
+where
+    V: Signed,
+    V::Scalar: Float + From<f32>,
+{
+}
+
 /// Complex valued vectors.
 pub trait Complex: Signed {
     /// The real scalar type.
@@ -258,3 +826,61 @@ pub trait Complex: Signed {
     /// Multiply by -i.
     fn mul_neg_i(self) -> Self;
 }
+
+/// Numeric conversion to vectors with the same number of lanes.
+///
+/// Conversions are provided from `f32` to `f64`, from `f64` to `f32`, and from `i32` to `f32`
+/// and `f64`.  For conversions from floating-point to integer vectors, see [`ConvertToInt`].
+///
+/// [`ConvertToInt`]: trait.ConvertToInt.html
+pub trait Convert<To>: Vector
+where
+    To: Vector,
+{
+    /// Converts each lane, as if by `as`.
+    fn convert(self, token: To::Token) -> To;
+
+    /// Converts each lane, as if by `as`, and splits the result into vectors containing the low
+    /// and high halves of the lanes.
+    ///
+    /// This is useful for widening conversions that produce two native vectors.
+    #[inline]
+    fn convert_split<Half>(self, token: To::Token) -> (Half, Half)
+    where
+        Half: Vector<Scalar = To::Scalar, Token = To::Token>,
+        Half::Width: width::Multiply<2, Product = To::Width>,
+    {
+        let converted = self.convert(token);
+        let lanes = converted.as_slice();
+        unsafe {
+            (
+                Half::read_unchecked(token, lanes),
+                Half::read_unchecked(token, &lanes[Half::width()..]),
+            )
+        }
+    }
+}
+
+/// Numeric conversion from floating-point vectors to integer vectors with the same number of
+/// lanes.
+pub trait ConvertToInt<To>: Vector
+where
+    To: Vector,
+{
+    /// Converts each lane, rounding toward zero.
+    ///
+    /// Lanes containing NaN or values that are out of range of the integer type produce
+    /// unspecified values.
+    fn convert_truncate(self, token: To::Token) -> To;
+
+    /// Converts each lane, rounding to the nearest integer with ties to even.
+    ///
+    /// Lanes containing NaN or values that are out of range of the integer type produce
+    /// unspecified values.
+    fn convert_round(self, token: To::Token) -> To;
+
+    /// Converts each lane, rounding toward zero and saturating to the range of the integer type.
+    ///
+    /// Lanes containing NaN produce zero.
+    fn convert_saturate(self, token: To::Token) -> To;
+}
@@ -5,30 +5,69 @@ pub trait Width {
     const VALUE: usize;
 }
 
+/// Indicates a vector contains `N` lanes.
+pub struct Lanes<const N: usize>;
+
+impl<const N: usize> Width for Lanes<N> {
+    const VALUE: usize = N;
+}
+
 /// Indicates a vector contains 1 lane.
-pub struct W1;
+pub type W1 = Lanes<1>;
 
 /// Indicates a vector contains 2 lanes.
-pub struct W2;
+pub type W2 = Lanes<2>;
 
 /// Indicates a vector contains 4 lanes.
-pub struct W4;
+pub type W4 = Lanes<4>;
 
 /// Indicates a vector contains 8 lanes.
-pub struct W8;
+pub type W8 = Lanes<8>;
+
+/// Indicates a vector contains 16 lanes.
+pub type W16 = Lanes<16>;
+
+/// Indicates a vector contains 32 lanes.
+pub type W32 = Lanes<32>;
+
+/// Indicates the array type containing one element per lane.
+///
+/// # Safety
+/// `Array` must be an array of `T` with length `VALUE`.
+pub unsafe trait ArrayOf<T>: Width {
+    type Array: Copy + AsRef<[T]> + AsMut<[T]>;
+}
+
+unsafe impl<T: Copy, const N: usize> ArrayOf<T> for Lanes<N> {
+    type Array = [T; N];
+}
 
-impl Width for W1 {
-    const VALUE: usize = 1;
+/// Determines the width of `N` vectors concatenated.
+pub trait Multiply<const N: usize>: Width {
+    type Product: Width;
 }
 
-impl Width for W2 {
-    const VALUE: usize = 2;
+impl<const N: usize> Multiply<N> for Lanes<1> {
+    type Product = Lanes<N>;
 }
 
-impl Width for W4 {
-    const VALUE: usize = 4;
+// A generic product would need `Lanes<{ W * N }>`, which requires `generic_const_exprs`, so the
+// wider widths are listed up to 32 lanes.
+macro_rules! multiply_impl {
+    { $($width:literal * [$($n:literal),*]),* } => {
+        $(
+            $(
+            impl Multiply<$n> for Lanes<$width> {
+                type Product = Lanes<{ $width * $n }>;
+            }
+            )*
+        )*
+    }
 }
 
-impl Width for W8 {
-    const VALUE: usize = 8;
+multiply_impl! {
+    2 * [2, 4, 8, 16],
+    4 * [2, 4, 8],
+    8 * [2, 4],
+    16 * [2]
 }